@@ -0,0 +1,187 @@
+use crate::{
+    helpers::{input_dirs, DEFAULT_INPUT},
+    solution::Answers,
+};
+use eyre::{Error, Result};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The answers file sits next to the inputs: in the first of [`input_dirs`] that has one,
+/// or else the first that exists, so recording from anywhere starts the right file.
+pub fn answers_path() -> PathBuf {
+    let dirs = input_dirs();
+    dirs.iter()
+        .map(|dir| dir.join(ANSWERS_FILE))
+        .find(|path| path.is_file())
+        .or_else(|| {
+            dirs.iter()
+                .find(|dir| dir.is_dir())
+                .map(|dir| dir.join(ANSWERS_FILE))
+        })
+        .unwrap_or_else(|| dirs[0].join(ANSWERS_FILE))
+}
+
+/// Recorded puzzle answers keyed by `(day, input name, part)`.
+///
+/// Stored as a small TOML subset: one `[dayNN]` table per day (or `[dayNN.name]` for
+/// inputs other than the real one) with `part1`/`part2` basic strings, so multi-line
+/// answers survive as escaped `\n`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerFile(BTreeMap<(u8, String, u8), String>);
+
+impl AnswerFile {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<AnswerFile> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(AnswerFile::default());
+        }
+        fs::read_to_string(path)?.parse()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    pub fn get(&self, day: u8, name: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&(day, name.to_string(), part))
+            .map(|s| s.as_str())
+    }
+
+    pub fn record(&mut self, day: u8, name: &str, answers: &Answers) {
+        self.0
+            .insert((day, name.to_string(), 1), answers.part1.clone());
+        self.0
+            .insert((day, name.to_string(), 2), answers.part2.clone());
+    }
+}
+
+impl FromStr for AnswerFile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        let mut table = None;
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix("[day").and_then(|l| l.strip_suffix(']')) {
+                let (day, name) = header.split_once('.').unwrap_or((header, DEFAULT_INPUT));
+                table = Some((day.parse::<u8>()?, name.to_string()));
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::msg(format!("line {}: expected `key = value`", i + 1)))?;
+            let (day, name) = table
+                .clone()
+                .ok_or_else(|| Error::msg(format!("line {}: no [dayNN] table", i + 1)))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                k => return Err(Error::msg(format!("line {}: unknown key {}", i + 1, k))),
+            };
+            answers.insert((day, name, part), unescape(value.trim())?);
+        }
+        Ok(AnswerFile(answers))
+    }
+}
+
+impl Display for AnswerFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut last_table = None;
+        for ((day, name, part), answer) in self.0.iter() {
+            if last_table != Some((day, name)) {
+                if last_table.is_some() {
+                    writeln!(f)?;
+                }
+                match name.as_str() {
+                    DEFAULT_INPUT => writeln!(f, "[day{:02}]", day)?,
+                    name => writeln!(f, "[day{:02}.{}]", day, name)?,
+                }
+                last_table = Some((day, name));
+            }
+            writeln!(f, "part{} = \"{}\"", part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> Result<String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| Error::msg(format!("Not a quoted string: {}", s)))?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some('"') => out.push('"'),
+            other => return Err(Error::msg(format!("Unsupported escape: \\{:?}", other))),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = "# recorded answers
+        [day01]
+        part1 = \"7\"
+        part2 = \"5\"
+
+        [day13.example]
+        part1 = \"17\"
+        part2 = \"#.#\\n.#.\"";
+
+        let answers: AnswerFile = input.parse()?;
+        assert_eq!(Some("7"), answers.get(1, "real", 1));
+        assert_eq!(Some("#.#\n.#."), answers.get(13, "example", 2));
+        assert_eq!(None, answers.get(13, "real", 1));
+        assert_eq!(None, answers.get(2, "real", 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let mut answers = AnswerFile::default();
+        answers.record(
+            4,
+            "real",
+            &Answers {
+                part1: "4512".to_string(),
+                part2: "say \"hi\"\\\n".to_string(),
+            },
+        );
+
+        assert_eq!(answers, answers.to_string().parse()?);
+
+        Ok(())
+    }
+}
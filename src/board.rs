@@ -1,27 +1,25 @@
-use eyre::{Error, Result};
+use crate::{
+    helpers::{ColumnError, Delimiter, ParseError},
+    render::Renderer,
+};
+use eyre::{Error, Report, Result};
 use itertools::Itertools;
-use std::{fmt::Debug, ops::AddAssign, str::FromStr};
+use std::{
+    cmp::Ordering,
+    collections::{btree_map, BTreeMap, HashSet, VecDeque},
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
 
+/// A board of single digits, one row per line.
+#[derive(Clone, Copy)]
 pub struct IntBoard<const X: usize, const Y: usize>(pub Board<u32, X, Y>);
 impl<const X: usize, const Y: usize> FromStr for IntBoard<X, Y> {
     type Err = Error;
 
     fn from_str(input: &str) -> Result<IntBoard<X, Y>> {
-        Ok(Self(Board::new(
-            input
-                .lines()
-                .map(|line| -> Result<[u32; X]> {
-                    line.trim()
-                        .chars()
-                        .map(|c| c.to_digit(10).unwrap())
-                        .collect::<Vec<_>>()
-                        .try_into()
-                        .map_err(|_v| Error::msg("Collect Failure"))
-                })
-                .collect::<Result<Vec<_>>>()?
-                .try_into()
-                .map_err(|_v| Error::msg("Collect Failure"))?,
-        )))
+        Ok(Self(input.parse::<IntGrid>()?.0.try_into()?))
     }
 }
 
@@ -33,51 +31,65 @@ impl<T, const X: usize, const Y: usize> Board<T, X, Y> {
     }
 }
 
+/// Cells padded to the widest one, so multi-digit values stay in columns.
 impl<T: Debug, const X: usize, const Y: usize> Debug for Board<T, X, Y> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in self.0.iter() {
-            for x in y.iter() {
-                x.fmt(f)?;
-            }
-            f.write_str("\n")?;
-        }
-        Ok(())
+        writeln!(f, "{}", debug_renderer().render(self))
     }
 }
 
+/// Rows are lines and cells are whitespace-separated tokens, as for `Grid`.
 impl<T, const X: usize, const Y: usize> FromStr for Board<T, X, Y>
 where
-    T: FromStr + Clone + Send + Sync + std::fmt::Debug,
-    T::Err: 'static + Send + Sync + std::error::Error,
+    T: FromStr,
+    T::Err: Into<Report>,
 {
     type Err = eyre::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.split_ascii_whitespace()
-                .map(|t| Ok(t.parse::<T>()?))
-                .collect::<Result<Vec<_>>>()?
-                .as_slice()
-                .chunks_exact(X)
-                .map(|chunk| -> Result<[T; X]> {
-                    chunk
-                        .iter()
-                        .map(|v| -> T { (*v).clone() })
-                        .collect::<Vec<T>>()
-                        .try_into()
-                        .map_err(|_v| {
-                            Error::msg(format!("Couldn't make correctly sized X Board: {:?}", _v))
-                        })
-                })
-                .collect::<Result<Vec<_>>>()?
-                .try_into()
-                .map_err(|_v| {
-                    eyre::Error::msg(format!("Couldn't make a correctly sized Board: {:?}", _v))
-                })?,
-        ))
+        s.parse::<Grid<T>>()?.try_into()
     }
 }
+
 impl<T, const X: usize, const Y: usize> Board<T, X, Y> {
+    pub fn width(&self) -> usize {
+        X
+    }
+
+    pub fn height(&self) -> usize {
+        Y
+    }
+
+    /// Up, down, left and right of `p`, where they're on the board.
+    pub fn neighbors(&self, p: &Point) -> impl Iterator<Item = Point> {
+        bounded(*p, X, Y, Coord::ORTHOGONAL.into_iter())
+    }
+
+    /// All eight cells around `p`, where they're on the board.
+    pub fn neighbors_with_diagonals(&self, p: &Point) -> impl Iterator<Item = Point> {
+        bounded(
+            *p,
+            X,
+            Y,
+            Coord::ORTHOGONAL.into_iter().chain(Coord::DIAGONAL),
+        )
+    }
+
+    /// Up, down, left and right of `p`, wrapping around the edges.
+    pub fn wrapping_neighbors(&self, p: &Point) -> impl Iterator<Item = Point> {
+        wrapping(*p, X, Y, Coord::ORTHOGONAL.into_iter())
+    }
+
+    /// All eight cells around `p`, wrapping around the edges.
+    pub fn wrapping_neighbors_with_diagonals(&self, p: &Point) -> impl Iterator<Item = Point> {
+        wrapping(
+            *p,
+            X,
+            Y,
+            Coord::ORTHOGONAL.into_iter().chain(Coord::DIAGONAL),
+        )
+    }
+
     pub fn get(&self, p: &Point) -> &T {
         &self.0[p.y][p.x]
     }
@@ -127,6 +139,535 @@ impl<T, const X: usize, const Y: usize> Board<T, X, Y> {
     }
 }
 
+/// Reshaping, each returning a new board.
+impl<T: Clone, const X: usize, const Y: usize> Board<T, X, Y> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Board<T, Y, X> {
+        Board(std::array::from_fn(|y| {
+            std::array::from_fn(|x| self.0[x][y].clone())
+        }))
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate_right(&self) -> Board<T, Y, X> {
+        Board(std::array::from_fn(|y| {
+            std::array::from_fn(|x| self.0[Y - 1 - x][y].clone())
+        }))
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Board<T, Y, X> {
+        Board(std::array::from_fn(|y| {
+            std::array::from_fn(|x| self.0[x][X - 1 - y].clone())
+        }))
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Board<T, X, Y> {
+        Board(std::array::from_fn(|y| {
+            std::array::from_fn(|x| self.0[y][X - 1 - x].clone())
+        }))
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Board<T, X, Y> {
+        Board(std::array::from_fn(|y| self.0[Y - 1 - y].clone()))
+    }
+
+    /// See `Grid::crop`.
+    pub fn crop(&self, top_left: &Point, width: usize, height: usize) -> Grid<T> {
+        Grid::from(self.clone()).crop(top_left, width, height)
+    }
+
+    /// See `Grid::tile`.
+    pub fn tile(&self, across: usize, down: usize, f: impl FnMut(&T, Point) -> T) -> Grid<T> {
+        Grid::from(self.clone()).tile(across, down, f)
+    }
+
+    /// See `Grid::fold_up`.
+    pub fn fold_up(&self, y: usize, merge: impl FnMut(&T, &T) -> T) -> Grid<T> {
+        Grid::from(self.clone()).fold_up(y, merge)
+    }
+
+    /// See `Grid::fold_left`.
+    pub fn fold_left(&self, x: usize, merge: impl FnMut(&T, &T) -> T) -> Grid<T> {
+        Grid::from(self.clone()).fold_left(x, merge)
+    }
+}
+
+/// A grid of single digits, one row per line.
+#[derive(Clone)]
+pub struct IntGrid(pub Grid<u32>);
+impl FromStr for IntGrid {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<IntGrid> {
+        Ok(Self(parse_chars(input, |c| c.to_digit(10))?))
+    }
+}
+
+/// A map of `#` for true and `.` for false, one row per line.
+#[derive(Clone)]
+pub struct BoolGrid(pub Grid<bool>);
+impl FromStr for BoolGrid {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<BoolGrid> {
+        Ok(Self(parse_chars(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?))
+    }
+}
+
+/// One cell per character, with `f` saying what each character means or `None` if it
+/// isn't allowed. Blank lines are skipped and every row must be the same width.
+///
+/// Failures are [`ParseError`]s pointing at the offending character.
+pub fn parse_chars<T>(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+    let mut rows = Vec::new();
+    for (number, line) in input.lines().enumerate() {
+        let text = line.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let indent = line.chars().count() - line.trim_start().chars().count();
+        let error = |column: usize, message: String| -> Report {
+            ParseError::new(
+                number + 1,
+                line,
+                ColumnError::new(indent + column, message).into(),
+            )
+            .into()
+        };
+        let row = text
+            .chars()
+            .enumerate()
+            .map(|(x, c)| f(c).ok_or_else(|| error(x, format!("{:?} isn't a valid cell", c))))
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(first) = rows.first().map(Vec::len).filter(|w| *w != row.len()) {
+            let message = format!("Row is {} cells wide, expected {}", row.len(), first);
+            return Err(error(row.len().min(first), message));
+        }
+        rows.push(row);
+    }
+    Grid::from_rows(rows)
+}
+
+/// A heap-backed board whose dimensions are only known once the input is read.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Fills each cell with `f` of its position, a row at a time.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(Point { x, y }));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::msg(format!(
+                    "Row {} has {} cells, expected {}",
+                    y,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    /// Up, down, left and right of `p`, where they're on the grid.
+    pub fn neighbors(&self, p: &Point) -> impl Iterator<Item = Point> {
+        bounded(*p, self.width, self.height, Coord::ORTHOGONAL.into_iter())
+    }
+
+    /// All eight cells around `p`, where they're on the grid.
+    pub fn neighbors_with_diagonals(&self, p: &Point) -> impl Iterator<Item = Point> {
+        let deltas = Coord::ORTHOGONAL.into_iter().chain(Coord::DIAGONAL);
+        bounded(*p, self.width, self.height, deltas)
+    }
+
+    /// Up, down, left and right of `p`, wrapping around the edges.
+    pub fn wrapping_neighbors(&self, p: &Point) -> impl Iterator<Item = Point> {
+        wrapping(*p, self.width, self.height, Coord::ORTHOGONAL.into_iter())
+    }
+
+    /// All eight cells around `p`, wrapping around the edges.
+    pub fn wrapping_neighbors_with_diagonals(&self, p: &Point) -> impl Iterator<Item = Point> {
+        let deltas = Coord::ORTHOGONAL.into_iter().chain(Coord::DIAGONAL);
+        wrapping(*p, self.width, self.height, deltas)
+    }
+
+    pub fn get(&self, p: &Point) -> &T {
+        &self.cells[self.index(p)]
+    }
+
+    pub fn set(&mut self, p: &Point, v: T) {
+        let i = self.index(p);
+        self.cells[i] = v;
+    }
+
+    pub fn incr(&mut self, p: &Point)
+    where
+        T: AddAssign<u32>,
+    {
+        let i = self.index(p);
+        self.cells[i] += 1;
+    }
+
+    pub fn find(&self, v: T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.cells.iter().position(|c| *c == v).map(|i| Point {
+            x: i % self.width,
+            y: i / self.width,
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Vec<&T> {
+        self.cells[y * self.width..(y + 1) * self.width]
+            .iter()
+            .collect()
+    }
+
+    pub fn col(&self, x: usize) -> Vec<&T> {
+        // A zero-width grid has no cells, so the `max` only keeps `chunks` from panicking.
+        self.cells
+            .chunks(self.width.max(1))
+            .map(|row| &row[x])
+            .collect()
+    }
+
+    pub fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = &'a T> + 'a> {
+        Box::new(self.cells.iter())
+    }
+
+    pub fn points(&self) -> Vec<Point> {
+        (0..self.width)
+            .cartesian_product(0..self.height)
+            .map(|(x, y)| Point { x, y })
+            .collect()
+    }
+
+    fn index(&self, p: &Point) -> usize {
+        assert!(self.contains(p), "{:?} is outside the grid", p);
+        p.y * self.width + p.x
+    }
+}
+
+/// Reshaping, each returning a new grid.
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self.get(&Point { x: p.y, y: p.x }).clone()
+        })
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            let y = self.height - 1 - p.x;
+            self.get(&Point { x: p.y, y }).clone()
+        })
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            let x = self.width - 1 - p.y;
+            self.get(&Point { x, y: p.x }).clone()
+        })
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |p| {
+            let x = self.width - 1 - p.x;
+            self.get(&Point { x, y: p.y }).clone()
+        })
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |p| {
+            let y = self.height - 1 - p.y;
+            self.get(&Point { x: p.x, y }).clone()
+        })
+    }
+
+    /// The `width` by `height` block starting at `top_left`, which must fit on the grid.
+    pub fn crop(&self, top_left: &Point, width: usize, height: usize) -> Grid<T> {
+        assert!(
+            top_left.x + width <= self.width && top_left.y + height <= self.height,
+            "{}x{} at {:?} doesn't fit in {}x{}",
+            width,
+            height,
+            top_left,
+            self.width,
+            self.height
+        );
+        Grid::from_fn(width, height, |p| {
+            let x = top_left.x + p.x;
+            let y = top_left.y + p.y;
+            self.get(&Point { x, y }).clone()
+        })
+    }
+
+    /// `across` by `down` copies side by side, each cell passed through `f` along with
+    /// which tile it's in.
+    pub fn tile(&self, across: usize, down: usize, mut f: impl FnMut(&T, Point) -> T) -> Grid<T> {
+        Grid::from_fn(self.width * across, self.height * down, |p| {
+            let cell = Point {
+                x: p.x % self.width,
+                y: p.y % self.height,
+            };
+            let tile = Point {
+                x: p.x / self.width,
+                y: p.y / self.height,
+            };
+            f(self.get(&cell), tile)
+        })
+    }
+
+    /// Folds the rows below `y` up over the rows above it, dropping row `y`. Where two
+    /// cells land on each other they're combined with `merge(above, below)`.
+    ///
+    /// The result is as tall as the longer side, lined up against the fold.
+    pub fn fold_up(&self, y: usize, mut merge: impl FnMut(&T, &T) -> T) -> Grid<T> {
+        assert!(y < self.height, "Can't fold {} rows at {}", self.height, y);
+        let height = y.max(self.height - 1 - y);
+        Grid::from_fn(self.width, height, |p| {
+            let from_fold = height - p.y;
+            let above = y.checked_sub(from_fold).map(|y| Point { x: p.x, y });
+            let below = Some(Point {
+                x: p.x,
+                y: y + from_fold,
+            })
+            .filter(|pt| pt.y < self.height);
+            match (above, below) {
+                (Some(a), Some(b)) => merge(self.get(&a), self.get(&b)),
+                (Some(only), None) | (None, Some(only)) => self.get(&only).clone(),
+                (None, None) => unreachable!("every row is on one side of the fold"),
+            }
+        })
+    }
+
+    /// Folds the columns right of `x` over the columns left of it, as `fold_up` does rows.
+    pub fn fold_left(&self, x: usize, merge: impl FnMut(&T, &T) -> T) -> Grid<T> {
+        self.transpose().fold_up(x, merge).transpose()
+    }
+}
+
+/// Cells padded to the widest one, so multi-digit values stay in columns.
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", debug_renderer().render(self))
+    }
+}
+
+fn debug_renderer<'a, T: Debug>() -> Renderer<'a, T> {
+    Renderer::debug().auto_width().separator(" ").plain()
+}
+
+/// Rows are lines and cells are whitespace-separated tokens, so cells can be several
+/// characters wide. Blank lines are skipped.
+impl<T> FromStr for Grid<T>
+where
+    T: FromStr,
+    T::Err: Into<Report>,
+{
+    type Err = eyre::Error;
+
+    /// Whitespace-separated cells, one row per non-blank line. A row of the wrong width
+    /// is a [`ParseError`] pointing at its first extra cell, or the end of a short row.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        let lines = s.lines().zip(Delimiter::whitespace().parse_lines(s)?);
+        for (i, (line, row)) in lines.enumerate() {
+            if row.is_empty() {
+                continue;
+            }
+            if let Some(first) = rows.first().map(Vec::len).filter(|w| *w != row.len()) {
+                let column = match line.split_whitespace().nth(first) {
+                    Some(extra) => line[..extra.as_ptr() as usize - line.as_ptr() as usize]
+                        .chars()
+                        .count(),
+                    None => line.trim_end().chars().count(),
+                };
+                let message = format!("Row is {} cells wide, expected {}", row.len(), first);
+                return Err(
+                    ParseError::new(i + 1, line, ColumnError::new(column, message).into()).into(),
+                );
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T, const X: usize, const Y: usize> From<Board<T, X, Y>> for Grid<T> {
+    fn from(board: Board<T, X, Y>) -> Grid<T> {
+        Grid {
+            width: X,
+            height: Y,
+            cells: board.0.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T, const X: usize, const Y: usize> TryFrom<Grid<T>> for Board<T, X, Y> {
+    type Error = Error;
+
+    fn try_from(grid: Grid<T>) -> Result<Board<T, X, Y>> {
+        if (grid.width, grid.height) != (X, Y) {
+            return Err(Error::msg(format!(
+                "Grid is {}x{}, Board is {}x{}",
+                grid.width, grid.height, X, Y
+            )));
+        }
+        let mut cells = grid.cells.into_iter();
+        Ok(Board::new(std::array::from_fn(|_| {
+            std::array::from_fn(|_| cells.next().expect("sizes checked"))
+        })))
+    }
+}
+
+/// Read access shared by `Board` and `Grid`, so the searches in `crate::search` can take
+/// either.
+pub trait Cells {
+    type Cell;
+
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn get(&self, p: &Point) -> &Self::Cell;
+
+    /// Up, down, left and right of `p`, where they're in bounds.
+    fn neighbors(&self, p: &Point) -> impl Iterator<Item = Point> {
+        bounded(
+            *p,
+            self.width(),
+            self.height(),
+            Coord::ORTHOGONAL.into_iter(),
+        )
+    }
+}
+
+impl<T, const X: usize, const Y: usize> Cells for Board<T, X, Y> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        X
+    }
+
+    fn height(&self) -> usize {
+        Y
+    }
+
+    fn get(&self, p: &Point) -> &T {
+        Board::get(self, p)
+    }
+}
+
+impl<T> Cells for Grid<T> {
+    type Cell = T;
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, p: &Point) -> &T {
+        Grid::get(self, p)
+    }
+}
+
+fn bounded(
+    p: Point,
+    width: usize,
+    height: usize,
+    deltas: impl Iterator<Item = Coord>,
+) -> impl Iterator<Item = Point> {
+    deltas.filter_map(move |d| {
+        let n = (Coord::from(p) + d).to_point()?;
+        (n.x < width && n.y < height).then_some(n)
+    })
+}
+
+/// On boards narrower than 3 cells a wrapped neighbor can repeat, or be `p` itself;
+/// `p` is skipped but repeats are not.
+fn wrapping(
+    p: Point,
+    width: usize,
+    height: usize,
+    deltas: impl Iterator<Item = Coord>,
+) -> impl Iterator<Item = Point> {
+    deltas
+        .map(move |d| (Coord::from(p) + d).wrap(width, height))
+        .filter(move |n| *n != p)
+}
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Point {
     pub x: usize,
@@ -153,3 +694,743 @@ impl FromStr for Point {
         })
     }
 }
+
+/// A position or offset on an unbounded plane, where coordinates can go negative. `y` grows
+/// downwards, like board rows.
+///
+/// Ordered row-major, by `y` then `x`.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Coord {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Coord {
+    pub const ZERO: Coord = Coord { x: 0, y: 0 };
+    pub const UP: Coord = Coord { x: 0, y: -1 };
+    pub const DOWN: Coord = Coord { x: 0, y: 1 };
+    pub const LEFT: Coord = Coord { x: -1, y: 0 };
+    pub const RIGHT: Coord = Coord { x: 1, y: 0 };
+    pub const ORTHOGONAL: [Coord; 4] = [Coord::UP, Coord::LEFT, Coord::RIGHT, Coord::DOWN];
+    pub const DIAGONAL: [Coord; 4] = [
+        Coord { x: -1, y: -1 },
+        Coord { x: 1, y: -1 },
+        Coord { x: -1, y: 1 },
+        Coord { x: 1, y: 1 },
+    ];
+
+    pub fn new(x: i64, y: i64) -> Coord {
+        Coord { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Coord) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal steps count as one, like a king in chess.
+    pub fn chebyshev(&self, other: &Coord) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Each component reduced to -1, 0 or 1: the single step heading towards `self`.
+    pub fn signum(&self) -> Coord {
+        Coord::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotated a quarter turn clockwise, as seen on screen: `UP` becomes `RIGHT`.
+    pub fn turn_right(&self) -> Coord {
+        Coord::new(-self.y, self.x)
+    }
+
+    /// Rotated a quarter turn anticlockwise, as seen on screen: `UP` becomes `LEFT`.
+    pub fn turn_left(&self) -> Coord {
+        Coord::new(self.y, -self.x)
+    }
+
+    /// The same place as a [`Point`], or `None` if either component is negative. Unlike
+    /// `Point::try_from` this builds no error, for hot loops that expect misses.
+    pub fn to_point(&self) -> Option<Point> {
+        Some(Point {
+            x: self.x.try_into().ok()?,
+            y: self.y.try_into().ok()?,
+        })
+    }
+
+    /// Wrapped around the edges onto a `width` by `height` board.
+    pub fn wrap(&self, width: usize, height: usize) -> Point {
+        Point {
+            x: self.x.rem_euclid(width as i64) as usize,
+            y: self.y.rem_euclid(height as i64) as usize,
+        }
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, rhs: Coord) -> Coord {
+        Coord::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Coord) -> Coord {
+        Coord::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, rhs: Coord) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Coord {
+    type Output = Coord;
+
+    fn mul(self, rhs: i64) -> Coord {
+        Coord::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord::new(-self.x, -self.y)
+    }
+}
+
+impl Debug for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(format!("({}, {})", self.x, self.y).as_str())
+    }
+}
+
+impl Ord for Coord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Coord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Point> for Coord {
+    fn from(p: Point) -> Coord {
+        Coord {
+            x: p.x as i64,
+            y: p.y as i64,
+        }
+    }
+}
+
+/// Fails if either coordinate is negative; whether it's on a given board is up to the board.
+impl TryFrom<Coord> for Point {
+    type Error = Error;
+
+    fn try_from(c: Coord) -> Result<Point> {
+        c.to_point()
+            .ok_or_else(|| Error::msg(format!("{:?} is off the board", c)))
+    }
+}
+
+impl FromStr for Coord {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .trim()
+            .split_once(',')
+            .ok_or_else(|| Error::msg("No comma"))?;
+        Ok(Coord {
+            x: x.trim().parse()?,
+            y: y.trim().parse()?,
+        })
+    }
+}
+
+/// Cells on an unbounded plane; only the ones that have been set take up space.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseGrid<T>(BTreeMap<Coord, T>);
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid(BTreeMap::new())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, p: &Coord) -> bool {
+        self.0.contains_key(p)
+    }
+
+    pub fn get(&self, p: &Coord) -> Option<&T> {
+        self.0.get(p)
+    }
+
+    pub fn get_mut(&mut self, p: &Coord) -> Option<&mut T> {
+        self.0.get_mut(p)
+    }
+
+    /// Sets `p`, returning whatever was there before.
+    pub fn insert(&mut self, p: Coord, v: T) -> Option<T> {
+        self.0.insert(p, v)
+    }
+
+    pub fn remove(&mut self, p: &Coord) -> Option<T> {
+        self.0.remove(p)
+    }
+
+    pub fn entry(&mut self, p: Coord) -> btree_map::Entry<'_, Coord, T> {
+        self.0.entry(p)
+    }
+
+    /// How many set cells match `f`.
+    pub fn count(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        self.0.values().filter(|v| f(v)).count()
+    }
+
+    /// Set cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Coord, &T)> {
+        self.0.iter()
+    }
+
+    /// Top-left and bottom-right corners of the set cells, inclusive.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        let first = self.0.keys().next()?;
+        Some(self.0.keys().fold((*first, *first), |(lo, hi), p| {
+            (
+                Coord {
+                    x: lo.x.min(p.x),
+                    y: lo.y.min(p.y),
+                },
+                Coord {
+                    x: hi.x.max(p.x),
+                    y: hi.y.max(p.y),
+                },
+            )
+        }))
+    }
+
+    /// Draws the bounding box one character per cell, with unset cells passed as `None`.
+    pub fn render(&self, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let Some((lo, hi)) = self.bounds() else {
+            return String::new();
+        };
+        (lo.y..=hi.y)
+            .map(|y| {
+                (lo.x..=hi.x)
+                    .map(|x| f(self.get(&Coord { x, y })))
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+impl<T: Debug> Debug for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.0.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> SparseGrid<T> {
+        SparseGrid(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<(Coord, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Coord, T)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+/// A position or offset in 3D space.
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Coord3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Coord3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Coord3 {
+        Coord3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Coord3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The six cells sharing a face with this one.
+    pub fn neighbors(&self) -> impl Iterator<Item = Coord3> {
+        let p = *self;
+        [
+            Coord3::new(-1, 0, 0),
+            Coord3::new(1, 0, 0),
+            Coord3::new(0, -1, 0),
+            Coord3::new(0, 1, 0),
+            Coord3::new(0, 0, -1),
+            Coord3::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |d| p + d)
+    }
+
+    /// The 26 cells sharing a face, edge or corner with this one.
+    pub fn neighbors_with_diagonals(&self) -> impl Iterator<Item = Coord3> {
+        let p = *self;
+        (-1..=1)
+            .cartesian_product(-1..=1)
+            .cartesian_product(-1..=1)
+            .filter(|&((x, y), z)| (x, y, z) != (0, 0, 0))
+            .map(move |((x, y), z)| p + Coord3::new(x, y, z))
+    }
+
+    /// This point under each of the 24 rotations that keep axes aligned, identity first.
+    /// The order is the same for every point, so index `i` is the same rotation throughout.
+    pub fn rotations(&self) -> Vec<Coord3> {
+        let axes = [self.x, self.y, self.z];
+        (0..3)
+            .permutations(3)
+            .cartesian_product(0..8)
+            .filter(|(perm, signs): &(Vec<usize>, u32)| {
+                // Odd permutations and odd numbers of flips each mirror; a rotation does
+                // both or neither.
+                let swaps = perm.iter().tuple_combinations().filter(|(a, b)| a > b);
+                swaps.count() % 2 == signs.count_ones() as usize % 2
+            })
+            .map(|(perm, signs)| {
+                let axis = |i: usize| {
+                    let sign = if signs & (1 << i) == 0 { 1 } else { -1 };
+                    axes[perm[i]] * sign
+                };
+                Coord3::new(axis(0), axis(1), axis(2))
+            })
+            .collect()
+    }
+}
+
+impl Debug for Coord3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(format!("({}, {}, {})", self.x, self.y, self.z).as_str())
+    }
+}
+
+impl Add for Coord3 {
+    type Output = Coord3;
+
+    fn add(self, rhs: Coord3) -> Coord3 {
+        Coord3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, rhs: Coord3) -> Coord3 {
+        Coord3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Coord3 {
+    type Output = Coord3;
+
+    fn neg(self) -> Coord3 {
+        Coord3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl FromStr for Coord3 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y, z) = s
+            .trim()
+            .split(',')
+            .collect_tuple()
+            .ok_or_else(|| Error::msg("Expected x,y,z"))?;
+        Ok(Coord3 {
+            x: x.trim().parse()?,
+            y: y.trim().parse()?,
+            z: z.trim().parse()?,
+        })
+    }
+}
+
+/// A set of filled unit cubes in 3D space.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Voxels(HashSet<Coord3>);
+
+impl Voxels {
+    pub fn new() -> Voxels {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, p: &Coord3) -> bool {
+        self.0.contains(p)
+    }
+
+    /// Returns whether `p` was newly added.
+    pub fn insert(&mut self, p: Coord3) -> bool {
+        self.0.insert(p)
+    }
+
+    pub fn remove(&mut self, p: &Coord3) -> bool {
+        self.0.remove(p)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Coord3> {
+        self.0.iter()
+    }
+
+    /// Lowest and highest corners of the filled cubes, inclusive.
+    pub fn bounds(&self) -> Option<(Coord3, Coord3)> {
+        let first = *self.0.iter().next()?;
+        Some(self.0.iter().fold((first, first), |(lo, hi), p| {
+            (
+                Coord3::new(lo.x.min(p.x), lo.y.min(p.y), lo.z.min(p.z)),
+                Coord3::new(hi.x.max(p.x), hi.y.max(p.y), hi.z.max(p.z)),
+            )
+        }))
+    }
+
+    /// Faces not shared with another filled cube, including those facing enclosed pockets.
+    pub fn surface_area(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|p| p.neighbors())
+            .filter(|n| !self.contains(n))
+            .count()
+    }
+
+    /// The empty cells reachable from outside, within a box one cell bigger than the
+    /// bounds on every side.
+    pub fn exterior(&self) -> Voxels {
+        let Some((lo, hi)) = self.bounds() else {
+            return Voxels::new();
+        };
+        let (lo, hi) = (lo - Coord3::new(1, 1, 1), hi + Coord3::new(1, 1, 1));
+        let inside = |p: &Coord3| {
+            (lo.x..=hi.x).contains(&p.x)
+                && (lo.y..=hi.y).contains(&p.y)
+                && (lo.z..=hi.z).contains(&p.z)
+        };
+        let mut outside = Voxels::new();
+        outside.insert(lo);
+        let mut queue = VecDeque::from([lo]);
+        while let Some(p) = queue.pop_front() {
+            for n in p.neighbors() {
+                if inside(&n) && !self.contains(&n) && outside.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+        }
+        outside
+    }
+
+    /// Faces that can be reached from outside, ignoring enclosed pockets.
+    pub fn exterior_surface_area(&self) -> usize {
+        let outside = self.exterior();
+        self.0
+            .iter()
+            .flat_map(|p| p.neighbors())
+            .filter(|n| outside.contains(n))
+            .count()
+    }
+}
+
+impl FromIterator<Coord3> for Voxels {
+    fn from_iter<I: IntoIterator<Item = Coord3>>(iter: I) -> Voxels {
+        Voxels(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Coord3> for Voxels {
+    fn extend<I: IntoIterator<Item = Coord3>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+/// One "x,y,z" cube per line.
+impl FromStr for Voxels {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.parse())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let grid = "123\n456".parse::<IntGrid>()?.0;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(&6, grid.get(&Point { x: 2, y: 1 }));
+        assert_eq!(Some(Point { x: 1, y: 1 }), grid.find(5));
+        assert_eq!(vec![&4, &5, &6], grid.row(1));
+        assert_eq!(vec![&2, &5], grid.col(1));
+        assert!("".parse::<Grid<u32>>()?.col(0).is_empty());
+        let err = "1 2\n\n3 4 5".parse::<Grid<u32>>().unwrap_err();
+        let err = err.downcast_ref::<ParseError>().expect("a ParseError");
+        assert_eq!((3, Some(4)), (err.line, err.column));
+        assert!("12\n345".parse::<IntGrid>().is_err());
+        assert!("12\n3x".parse::<IntGrid>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let grid: Grid<u32> = "10 2\n3 40\n5 6".parse()?;
+        let board: Board<u32, 2, 3> = grid.clone().try_into()?;
+        assert_eq!(&40, board.get(&Point { x: 1, y: 1 }));
+        assert_eq!(grid, Grid::from(board));
+        assert!(Board::<u32, 3, 2>::try_from(grid).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() {
+        let board = Board::new([[0u32; 4]; 3]);
+        let pt = |x, y| Point { x, y };
+
+        let corner = board.neighbors(&pt(0, 0)).collect::<Vec<_>>();
+        assert_eq!(vec![pt(1, 0), pt(0, 1)], corner);
+        assert_eq!(4, board.neighbors(&pt(1, 1)).count());
+        assert_eq!(3, board.neighbors_with_diagonals(&pt(3, 2)).count());
+        assert_eq!(8, board.neighbors_with_diagonals(&pt(1, 1)).count());
+
+        let wrapped = board.wrapping_neighbors(&pt(0, 0)).collect::<Vec<_>>();
+        assert_eq!(vec![pt(0, 2), pt(3, 0), pt(1, 0), pt(0, 1)], wrapped);
+        assert!(board
+            .wrapping_neighbors_with_diagonals(&pt(3, 2))
+            .any(|p| p == pt(0, 0)));
+
+        let grid = Grid::from(board);
+        assert_eq!(
+            board
+                .neighbors_with_diagonals(&pt(0, 2))
+                .collect::<Vec<_>>(),
+            grid.neighbors_with_diagonals(&pt(0, 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn t4() -> Result<()> {
+        let c = |x, y| Coord { x, y };
+        let mut grid: SparseGrid<u32> = [(c(2, 1), 1), (c(-1, -2), 5)].into_iter().collect();
+        *grid.entry(c(2, 1)).or_default() += 1;
+        *grid.entry(c(0, 0)).or_default() += 1;
+
+        assert_eq!(3, grid.len());
+        assert_eq!(Some(&2), grid.get(&"2,1".parse()?));
+        assert_eq!(Some((c(-1, -2), c(2, 1))), grid.bounds());
+        assert_eq!(2, grid.count(|v| *v > 1));
+        assert_eq!(
+            vec![c(-1, -2), c(0, 0), c(2, 1)],
+            grid.iter().map(|(p, _)| *p).collect::<Vec<_>>()
+        );
+        let drawn = grid.render(|v| v.map_or('.', |n| char::from_digit(*n, 10).unwrap()));
+        assert_eq!("5...\n....\n.1..\n...2", drawn);
+        assert_eq!("", SparseGrid::<u32>::new().render(|_| '#'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t5() -> Result<()> {
+        let a = Coord::new(3, -4);
+        let b = Coord::new(-1, 2);
+
+        assert_eq!(Coord::new(2, -2), a + b);
+        assert_eq!(Coord::new(4, -6), a - b);
+        assert_eq!(Coord::new(-6, 8), a * -2);
+        assert_eq!(b, -(-b));
+        assert_eq!(10, a.manhattan(&b));
+        assert_eq!(6, a.chebyshev(&b));
+        assert_eq!(Coord::new(1, -1), a.signum());
+
+        assert_eq!(Coord::RIGHT, Coord::UP.turn_right());
+        assert_eq!(Coord::LEFT, Coord::UP.turn_left());
+        assert_eq!(a, a.turn_left().turn_left().turn_left().turn_left());
+        assert_eq!(-a, a.turn_right().turn_right());
+
+        assert_eq!(Point { x: 3, y: 2 }, Point::try_from(a + Coord::DOWN * 6)?);
+        assert!(Point::try_from(a).is_err());
+        assert_eq!(None, a.to_point());
+        assert_eq!(Some(Point { x: 3, y: 2 }), (a + Coord::DOWN * 6).to_point());
+        assert_eq!(Point { x: 3, y: 1 }, a.wrap(4, 5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t6() -> Result<()> {
+        let p: Coord3 = " 1, 2,3".parse()?;
+        assert_eq!(Coord3::new(1, 2, 3), p);
+        assert!("1,2".parse::<Coord3>().is_err());
+        assert_eq!(6, p.neighbors().count());
+        assert_eq!(26, p.neighbors_with_diagonals().unique().count());
+
+        let turned = p.rotations();
+        assert_eq!(24, turned.iter().unique().count());
+        assert_eq!(p, turned[0]);
+        assert!(!turned.contains(&Coord3::new(-1, 2, 3)));
+        let q = Coord3::new(-4, 0, 7);
+        let pairs = q.rotations().into_iter().zip(&turned).collect::<Vec<_>>();
+        assert!(pairs.iter().all(|(a, b)| a.manhattan(b) == q.manhattan(&p)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t7() -> Result<()> {
+        let droplet: Voxels = "2,2,2\n1,2,2\n3,2,2\n2,1,2\n2,3,2\n2,2,1\n2,2,3\n\
+                               2,2,4\n2,2,6\n1,2,5\n3,2,5\n2,1,5\n2,3,5"
+            .parse()?;
+        assert_eq!(13, droplet.len());
+        assert_eq!(64, droplet.surface_area());
+        assert_eq!(58, droplet.exterior_surface_area());
+        assert!(!droplet.exterior().contains(&Coord3::new(2, 2, 5)));
+        assert_eq!(10, "1,1,1\n2,1,1".parse::<Voxels>()?.surface_area());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t8() -> Result<()> {
+        let board = "123\n456".parse::<IntBoard<3, 2>>()?.0;
+        let rows = |g: Grid<u32>| {
+            (0..g.height())
+                .map(|y| g.row(y).into_iter().copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+            rows(board.transpose().into())
+        );
+        assert_eq!(
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]],
+            rows(board.rotate_right().into())
+        );
+        assert_eq!(
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]],
+            rows(board.rotate_left().into())
+        );
+        assert_eq!(
+            vec![vec![3, 2, 1], vec![6, 5, 4]],
+            rows(board.flip_horizontal().into())
+        );
+        assert_eq!(
+            vec![vec![4, 5, 6], vec![1, 2, 3]],
+            rows(board.flip_vertical().into())
+        );
+
+        let grid = Grid::from(board);
+        assert_eq!(Grid::from(board.rotate_right()), grid.rotate_right());
+        assert_eq!(Grid::from(board.rotate_left()), grid.rotate_left());
+        assert_eq!(grid, grid.rotate_right().rotate_left());
+        assert_eq!(grid, grid.transpose().transpose());
+        assert_eq!(Grid::from(board.flip_horizontal()), grid.flip_horizontal());
+        assert_eq!(
+            vec![vec![5, 6]],
+            rows(board.crop(&Point { x: 1, y: 1 }, 2, 1))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn t9() -> Result<()> {
+        let grid = "89\n91".parse::<IntGrid>()?.0;
+        let chitons = grid.tile(5, 5, |v, t| (v + t.x as u32 + t.y as u32 - 1) % 9 + 1);
+        assert_eq!((10, 10), (chitons.width(), chitons.height()));
+        assert_eq!(vec![&8, &9, &9, &1, &1, &2, &2, &3, &3, &4], chitons.row(0));
+        assert_eq!(&9, chitons.get(&Point { x: 9, y: 9 }));
+
+        let paper = "1 0 0 0 0\n0 0 0 0 0\n0 0 0 0 1\n0 1 0 0 0".parse::<Grid<u32>>()?;
+        let folded = paper.fold_up(1, |a, b| a + b);
+        assert_eq!(2, folded.height());
+        assert_eq!(vec![&0, &1, &0, &0, &0], folded.row(0));
+        assert_eq!(vec![&1, &0, &0, &0, &1], folded.row(1));
+        let folded = folded.fold_left(2, |a, b| a.max(b) + 10);
+        assert_eq!((2, 2), (folded.width(), folded.height()));
+        assert_eq!(vec![&10, &11], folded.row(0));
+        assert_eq!(vec![&11, &10], folded.row(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t10() -> Result<()> {
+        let err = "123\n4x6\n".parse::<IntBoard<3, 2>>().err().unwrap();
+        assert_eq!(
+            Some((2, Some(1))),
+            err.downcast_ref::<ParseError>().map(|e| (e.line, e.column))
+        );
+        let err = "123\n\n12".parse::<IntGrid>().err().unwrap();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((3, Some(2)), (err.line, err.column));
+        assert!("123\n456".parse::<IntBoard<3, 3>>().is_err());
+
+        let map = "#.#\n..#".parse::<BoolGrid>()?.0;
+        assert_eq!(3, map.iter().filter(|b| **b).count());
+        assert!(*map.get(&Point { x: 2, y: 1 }));
+        assert!("#.o".parse::<BoolGrid>().is_err());
+
+        let seats = parse_chars("L.\n#L", |c| "L#.".find(c))?;
+        assert_eq!(vec![&1, &0], seats.row(1));
+
+        let bingo: Board<u32, 3, 2> = " 1 22  3\n14  5 60\n".parse()?;
+        assert_eq!(&60, bingo.get(&Point { x: 2, y: 1 }));
+        let err = " 1 22  3\n14 x5 60"
+            .parse::<Board<u32, 3, 2>>()
+            .err()
+            .unwrap();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((2, Some(3)), (err.line, err.column));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    helpers::{std_parse, ParseError},
+    solution::Solution,
+};
+use eyre::{Error, Result};
+use std::{cmp::Ordering, collections::VecDeque, io::BufRead};
+
+pub struct Day01;
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        std_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        increases(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        window(input)
+    }
+}
+
+fn increases(input: &[i64]) -> usize {
+    Sweep::over(1, input).expect("nonzero width").trend.rising
+}
+
+fn window(input: &[i64]) -> usize {
+    Sweep::over(3, input).expect("nonzero width").trend.rising
+}
+
+/// The results of `stream`.
+#[derive(Debug, Clone)]
+pub struct Streamed {
+    /// Each reading against the one before: `trend.rising` answers part 1.
+    pub readings: Sweep,
+    /// Each window against the one before: with width 3, `trend.rising` answers part 2.
+    pub windows: Sweep,
+    /// Lines that weren't depths, only counted when lenient.
+    pub skipped: usize,
+}
+
+/// Both parts in one pass over `reader`, holding no more than `width` readings and one line
+/// at a time, so inputs can be far bigger than memory.
+///
+/// When `lenient`, lines that aren't depths, including ones that aren't UTF-8, are counted
+/// and skipped; otherwise the first one fails with a [`ParseError`].
+pub fn stream(mut reader: impl BufRead, width: usize, lenient: bool) -> Result<Streamed> {
+    let mut streamed = Streamed {
+        readings: Sweep::new(1)?,
+        windows: Sweep::new(width)?,
+        skipped: 0,
+    };
+    let mut line = Vec::new();
+    for number in 1.. {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        let depth = std::str::from_utf8(&line)
+            .map_err(Error::from)
+            .and_then(|text| Ok(text.trim().parse::<i64>()?));
+        match depth {
+            Ok(depth) => {
+                streamed.readings.push(depth);
+                streamed.windows.push(depth);
+            }
+            Err(_) if lenient => streamed.skipped += 1,
+            Err(e) => {
+                let text = String::from_utf8_lossy(&line);
+                return Err(ParseError::new(number, text.trim_end(), e).into());
+            }
+        }
+    }
+    Ok(streamed)
+}
+
+/// How sums of consecutive windows changed from one window to the next.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Trend {
+    pub rising: usize,
+    pub falling: usize,
+    pub flat: usize,
+}
+
+/// Analysis of a depth series, updated one reading at a time so it only ever holds
+/// `width` readings.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    width: usize,
+    recent: VecDeque<i64>,
+    sum: i64,
+    last_sum: Option<i64>,
+    run: usize,
+    /// Comparisons between each full window's sum and the one before it.
+    pub trend: Trend,
+    /// Most readings in a row that each went deeper than the one before.
+    pub longest_run: usize,
+    /// Biggest fall in depth between two consecutive readings, if it ever fell.
+    pub largest_drop: Option<i64>,
+}
+
+impl Sweep {
+    /// Fails if `width` is 0, as windows need at least one reading.
+    pub fn new(width: usize) -> Result<Sweep> {
+        if width == 0 {
+            return Err(Error::msg("Windows need at least one reading"));
+        }
+        Ok(Sweep {
+            width,
+            recent: VecDeque::with_capacity(width + 1),
+            sum: 0,
+            last_sum: None,
+            run: 0,
+            trend: Trend::default(),
+            longest_run: 0,
+            largest_drop: None,
+        })
+    }
+
+    pub fn over<'a>(width: usize, depths: impl IntoIterator<Item = &'a i64>) -> Result<Sweep> {
+        let mut sweep = Sweep::new(width)?;
+        for depth in depths {
+            sweep.push(*depth);
+        }
+        Ok(sweep)
+    }
+
+    pub fn push(&mut self, depth: i64) {
+        match self.recent.back() {
+            Some(last) if depth > *last => self.run += 1,
+            _ => self.run = 1,
+        }
+        self.longest_run = self.longest_run.max(self.run);
+        if let Some(drop) = self
+            .recent
+            .back()
+            .map(|last| *last - depth)
+            .filter(|d| *d > 0)
+        {
+            self.largest_drop = Some(self.largest_drop.map_or(drop, |d| d.max(drop)));
+        }
+
+        self.recent.push_back(depth);
+        self.sum += depth;
+        if self.recent.len() > self.width {
+            self.sum -= self.recent.pop_front().unwrap();
+        }
+        if self.recent.len() == self.width {
+            if let Some(last) = self.last_sum {
+                match self.sum.cmp(&last) {
+                    Ordering::Greater => self.trend.rising += 1,
+                    Ordering::Less => self.trend.falling += 1,
+                    Ordering::Equal => self.trend.flat += 1,
+                }
+            }
+            self.last_sum = Some(self.sum);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day01/example.txt");
+
+        assert_eq!(7, increases(&std_parse(input)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day01/example.txt");
+
+        assert_eq!(5, window(&std_parse(input)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let input = include_str!("../../days/day01/example.txt");
+
+        let sweep = Sweep::over(3, &std_parse(input)?)?;
+        assert_eq!(
+            Trend {
+                rising: 5,
+                falling: 1,
+                flat: 1
+            },
+            sweep.trend
+        );
+        assert_eq!(4, sweep.longest_run);
+        assert_eq!(Some(10), sweep.largest_drop);
+
+        let rising = Sweep::over(2, &[1, 2, 3, 4])?;
+        assert_eq!(2, rising.trend.rising);
+        assert_eq!(None, rising.largest_drop);
+        assert_eq!(Trend::default(), Sweep::over(5, &[1, 2, 3, 4])?.trend);
+        assert!(Sweep::over(0, &[1, 2]).is_err());
+        assert!(stream(input.as_bytes(), 0, true).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t4() -> Result<()> {
+        let input = include_str!("../../days/day01/example.txt");
+
+        let streamed = stream(input.as_bytes(), 3, false)?;
+        assert_eq!(7, streamed.readings.trend.rising);
+        assert_eq!(5, streamed.windows.trend.rising);
+        assert_eq!(0, streamed.skipped);
+
+        let noisy = input.replace("208\n", "208\nsurface\n\n");
+        let err = stream(noisy.as_bytes(), 3, false).err().unwrap();
+        assert_eq!(Some(4), err.downcast_ref::<ParseError>().map(|e| e.line));
+        let mut corrupt = input.as_bytes().to_vec();
+        corrupt.splice(0..0, *b"\xff\xfe\n");
+        assert!(stream(&corrupt[..], 3, false).is_err());
+        assert_eq!(1, stream(&corrupt[..], 3, true)?.skipped);
+        let streamed = stream(noisy.as_bytes(), 3, true)?;
+        assert_eq!(
+            (7, 5, 2),
+            (
+                streamed.readings.trend.rising,
+                streamed.windows.trend.rising,
+                streamed.skipped
+            )
+        );
+
+        Ok(())
+    }
+}
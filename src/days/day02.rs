@@ -0,0 +1,310 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Bound, RangeBounds},
+    str::FromStr,
+};
+
+use crate::{
+    board::Coord,
+    helpers::{std_parse, ParseError},
+    record::{Record, Recorder},
+    solution::Solution,
+};
+use eyre::{Error, Result};
+
+pub struct Day02;
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let commands = std_parse(input)?;
+        for rules in [Rules::part1(), Rules::part2()] {
+            rules.check(input, &commands)?;
+        }
+        Ok(commands)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        let sub = Rules::part1()
+            .run(input, &mut ())
+            .expect("checked in parse");
+        sub.horizontal * sub.depth
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        let sub = Rules::part2()
+            .run(input, &mut ())
+            .expect("checked in parse");
+        sub.horizontal * sub.depth
+    }
+}
+
+/// Everything a command can change.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sub {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Sub {
+    /// Where the sub is, for plotting its path: `x` is horizontal and `y` is depth.
+    pub fn position(&self) -> Coord {
+        Coord::new(self.horizontal, self.depth)
+    }
+}
+
+/// A command name and its amount, if it has one. What it does is up to the [`Rules`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    pub name: String,
+    pub amount: Option<i64>,
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let name = tokens
+            .next()
+            .ok_or_else(|| Error::msg("Empty command"))?
+            .to_string();
+        let amount = tokens.next().map(|a| a.parse()).transpose()?;
+        if tokens.next().is_some() {
+            return Err(Error::msg(format!("Too many words in {:?}", s)));
+        }
+        Ok(Command { name, amount })
+    }
+}
+
+enum Rule {
+    Bare(fn(&mut Sub)),
+    Amount(fn(&mut Sub, i64)),
+}
+
+/// The first command that took the sub's depth out of bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// 1-based, which is also the input line when parsed with `std_parse`.
+    pub line: usize,
+    pub command: Command,
+    /// Where the command left the sub.
+    pub sub: Sub,
+}
+
+impl Display for OutOfBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.command.name)?;
+        if let Some(n) = self.command.amount {
+            write!(f, " {}", n)?;
+        }
+        write!(f, " took the sub to depth {}", self.sub.depth)
+    }
+}
+
+impl std::error::Error for OutOfBounds {}
+
+/// What each command means, and optionally how deep the sub is allowed to go.
+pub struct Rules {
+    commands: HashMap<&'static str, Rule>,
+    depth: (Bound<i64>, Bound<i64>),
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            commands: HashMap::new(),
+            depth: (Bound::Unbounded, Bound::Unbounded),
+        }
+    }
+}
+
+impl Rules {
+    pub fn new() -> Rules {
+        Default::default()
+    }
+
+    /// Fails runs with [`OutOfBounds`] as soon as the depth leaves `bound`. `0..` catches
+    /// the sub surfacing.
+    pub fn depth_bound(self, bound: impl RangeBounds<i64>) -> Rules {
+        Rules {
+            depth: (bound.start_bound().cloned(), bound.end_bound().cloned()),
+            ..self
+        }
+    }
+
+    /// Down and up move the sub directly.
+    pub fn part1() -> Rules {
+        Rules::new()
+            .amount("forward", |sub, n| sub.horizontal += n)
+            .amount("down", |sub, n| sub.depth += n)
+            .amount("up", |sub, n| sub.depth -= n)
+    }
+
+    /// Down and up steer, and going forward follows the aim.
+    pub fn part2() -> Rules {
+        Rules::new()
+            .amount("forward", |sub, n| {
+                sub.horizontal += n;
+                sub.depth += sub.aim * n;
+            })
+            .amount("down", |sub, n| sub.aim += n)
+            .amount("up", |sub, n| sub.aim -= n)
+    }
+
+    /// Adds or replaces a command that takes an amount, like `forward 5`.
+    pub fn amount(mut self, name: &'static str, apply: fn(&mut Sub, i64)) -> Rules {
+        self.commands.insert(name, Rule::Amount(apply));
+        self
+    }
+
+    /// Adds or replaces a command on its own, like `reset`.
+    pub fn bare(mut self, name: &'static str, apply: fn(&mut Sub)) -> Rules {
+        self.commands.insert(name, Rule::Bare(apply));
+        self
+    }
+
+    /// Runs `commands` from the surface, handing the sub to `recorder` after each one,
+    /// including one that breaks the depth bound.
+    pub fn run(&self, commands: &[Command], recorder: &mut impl Record<Sub>) -> Result<Sub> {
+        let mut sub = Sub::default();
+        for (i, command) in commands.iter().enumerate() {
+            self.apply(&mut sub, command)
+                .map_err(|e| e.wrap_err(format!("Command {}", i + 1)))?;
+            recorder.record(&sub);
+            if !self.depth.contains(&sub.depth) {
+                return Err(OutOfBounds {
+                    line: i + 1,
+                    command: command.clone(),
+                    sub,
+                }
+                .into());
+            }
+        }
+        Ok(sub)
+    }
+
+    /// Where the sub is after each command.
+    pub fn trace(&self, commands: &[Command]) -> Result<Vec<Sub>> {
+        let mut recorder = Recorder::new(|sub: &Sub| *sub);
+        self.run(commands, &mut recorder)?;
+        Ok(recorder.into_frames())
+    }
+
+    /// The first command that takes the sub above the surface, if any. Commands these
+    /// rules can't run are still errors.
+    pub fn surfaces(self, commands: &[Command]) -> Result<Option<OutOfBounds>> {
+        match self.depth_bound(0..).run(commands, &mut ()) {
+            Ok(_) => Ok(None),
+            Err(e) => e.downcast::<OutOfBounds>().map(Some),
+        }
+    }
+
+    /// Fails on the first command these rules can't run, pointing at its line of `input`.
+    fn check(&self, input: &str, commands: &[Command]) -> Result<()> {
+        for ((i, line), command) in input.lines().enumerate().zip(commands) {
+            self.apply(&mut Sub::default(), command)
+                .map_err(|e| ParseError::new(i + 1, line, e))?;
+        }
+        Ok(())
+    }
+
+    fn apply(&self, sub: &mut Sub, command: &Command) -> Result<()> {
+        match (self.commands.get(command.name.as_str()), command.amount) {
+            (Some(Rule::Amount(apply)), Some(n)) => apply(sub, n),
+            (Some(Rule::Bare(apply)), None) => apply(sub),
+            (Some(Rule::Amount(_)), None) => {
+                return Err(Error::msg(format!("{} needs an amount", command.name)))
+            }
+            (Some(Rule::Bare(_)), Some(_)) => {
+                return Err(Error::msg(format!("{} takes no amount", command.name)))
+            }
+            (None, _) => return Err(Error::msg(format!("Unknown command {}", command.name))),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day02/example.txt");
+
+        let sub = Rules::part1().run(&std_parse(input)?, &mut ())?;
+        assert_eq!((sub.horizontal, sub.depth), (15, 10));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day02/example.txt");
+
+        let sub = Rules::part2().run(&std_parse(input)?, &mut ())?;
+        assert_eq!((sub.horizontal, sub.depth, sub.aim), (15, 60, 10));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let commands = std_parse("forward 5\ndown 2\nback 3\naim 4\nforward 1\nreset\nup 1")?;
+
+        let rules = Rules::part2()
+            .amount("back", |sub, n| sub.horizontal -= n)
+            .amount("aim", |sub, n| sub.aim = n)
+            .bare("reset", |sub| *sub = Sub::default());
+        let trace = rules.trace(&commands)?;
+        assert_eq!(7, trace.len());
+        assert_eq!(2, trace[2].horizontal);
+        assert_eq!((3, 4), (trace[4].horizontal, trace[4].depth));
+        assert_eq!(Sub::default(), trace[5]);
+        assert_eq!(-1, trace[6].aim);
+
+        let err = Rules::part2().run(&commands, &mut ()).err().unwrap();
+        assert_eq!("Command 3", err.to_string());
+        assert!(Day02::parse("forward 5\nforward").is_err());
+        assert!("down 1 2".parse::<Command>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t4() -> Result<()> {
+        let input = include_str!("../../days/day02/example.txt");
+        let commands: Vec<Command> = std_parse(input)?;
+
+        let path = Rules::part2().trace(&commands)?;
+        assert_eq!(commands.len(), path.len());
+        assert_eq!(Coord::new(15, 60), path.last().unwrap().position());
+        assert_eq!(None, Rules::part1().surfaces(&commands)?);
+
+        let err = Rules::part2()
+            .depth_bound(..=20)
+            .run(&commands, &mut ())
+            .err()
+            .unwrap();
+        let err = err.downcast_ref::<OutOfBounds>().unwrap();
+        assert_eq!((3, 40), (err.line, err.sub.depth));
+        assert_eq!(
+            "line 3: forward 8 took the sub to depth 40",
+            err.to_string()
+        );
+
+        let surfacing: Vec<Command> = std_parse("down 2\nforward 1\nup 3\ndown 5")?;
+        let surfaced = Rules::part1().surfaces(&surfacing)?.unwrap();
+        assert_eq!((3, -1), (surfaced.line, surfaced.sub.depth));
+
+        let sideways: Vec<Command> = std_parse("down 1\nsideways 3\nup 5")?;
+        let err = Rules::part1().surfaces(&sideways).unwrap_err();
+        assert_eq!("Command 2", err.to_string());
+
+        Ok(())
+    }
+}
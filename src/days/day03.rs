@@ -0,0 +1,215 @@
+use crate::{
+    helpers::{std_parse, ColumnError, ParseError},
+    solution::Solution,
+};
+use eyre::{Error, Result};
+use std::{fmt::Debug, str::FromStr};
+
+pub struct Day03;
+impl Solution for Day03 {
+    type Input = Report;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> u128 {
+        input.gamma() as u128 * input.epsilon() as u128
+    }
+
+    fn part2(input: &Self::Input) -> u128 {
+        input.oxygen() as u128 * input.co2() as u128
+    }
+}
+
+/// One binary number from the report, packed into an integer with its first character as
+/// the highest bit. Lines are at most 64 bits, so that the product of two always fits
+/// in the `u128` answers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    bits: u64,
+    width: usize,
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = 0u64;
+        for (i, c) in s.chars().enumerate() {
+            if i == u64::BITS as usize {
+                return Err(ColumnError::new(i, "Lines can be at most 64 bits").into());
+            }
+            let bit = c
+                .to_digit(2)
+                .ok_or_else(|| ColumnError::new(i, format!("{:?} is not a bit", c)))?;
+            bits = bits << 1 | bit as u64;
+        }
+        Ok(Line {
+            bits,
+            width: s.chars().count(),
+        })
+    }
+}
+
+impl Debug for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:0width$b}", self.bits, width = self.width)
+    }
+}
+
+/// The diagnostic report: lines of the same width, kept sorted so that lines sharing
+/// leading bits sit next to each other.
+#[derive(Debug, Clone)]
+pub struct Report {
+    lines: Vec<u64>,
+    width: usize,
+}
+
+impl FromStr for Report {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<Line> = std_parse(s)?;
+        let width = lines
+            .first()
+            .ok_or_else(|| Error::msg("Empty report"))?
+            .width;
+        if let Some(i) = lines.iter().position(|line| line.width != width) {
+            let message = format!("{} bits wide, expected {}", lines[i].width, width);
+            let text = s.lines().nth(i).unwrap_or_default();
+            return Err(ParseError::new(i + 1, text, Error::msg(message)).into());
+        }
+        let mut lines = lines.into_iter().map(|line| line.bits).collect::<Vec<_>>();
+        lines.sort_unstable();
+        Ok(Report { lines, width })
+    }
+}
+
+impl Report {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// How many lines have a 1 in each column, first column first.
+    pub fn popcounts(&self) -> Vec<usize> {
+        (0..self.width)
+            .map(|col| {
+                let mask = self.mask(col);
+                self.lines.iter().filter(|line| *line & mask != 0).count()
+            })
+            .collect()
+    }
+
+    /// The most common bit of each column, with ties going to 1.
+    pub fn gamma(&self) -> u64 {
+        let half = self.lines.len().div_ceil(2);
+        self.popcounts()
+            .into_iter()
+            .fold(0, |gamma, ones| gamma << 1 | (ones >= half) as u64)
+    }
+
+    /// The least common bit of each column, with ties going to 0.
+    pub fn epsilon(&self) -> u64 {
+        let all = u64::MAX.checked_shr(u64::BITS - self.width as u32);
+        !self.gamma() & all.unwrap_or(0)
+    }
+
+    /// Whittled down by keeping the most common bit of each column, ties keeping 1.
+    pub fn oxygen(&self) -> u64 {
+        self.rating(|zeros, ones| ones >= zeros)
+    }
+
+    /// Whittled down by keeping the least common bit of each column, ties keeping 0.
+    pub fn co2(&self) -> u64 {
+        self.rating(|zeros, ones| ones < zeros)
+    }
+
+    /// Narrows down to one line a column at a time, keeping the 1s when `keep_ones` says
+    /// so given how many 0s and 1s are left. A column where the lines left all agree
+    /// doesn't narrow anything.
+    ///
+    /// The lines still in the running share their leading bits, so in the sorted list
+    /// they're a contiguous range with the 0s of the next column before the 1s. Each
+    /// column is then a binary search instead of a pass over the lines.
+    fn rating(&self, keep_ones: impl Fn(usize, usize) -> bool) -> u64 {
+        let mut range = &self.lines[..];
+        for col in 0..self.width {
+            if range.len() <= 1 {
+                break;
+            }
+            let mask = self.mask(col);
+            let split = range.partition_point(|line| line & mask == 0);
+            let (zeros, ones) = range.split_at(split);
+            if zeros.is_empty() || ones.is_empty() {
+                continue;
+            }
+            range = if keep_ones(zeros.len(), ones.len()) {
+                ones
+            } else {
+                zeros
+            };
+        }
+        range[0]
+    }
+
+    /// The bit for column `col`, counting from the left.
+    fn mask(&self, col: usize) -> u64 {
+        1 << (self.width - 1 - col)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day03/example.txt");
+
+        let report: Report = input.parse()?;
+        assert_eq!((22, 9), (report.gamma(), report.epsilon()));
+        assert_eq!(vec![7, 5, 8, 7, 5], report.popcounts());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day03/example.txt");
+
+        let report: Report = input.parse()?;
+        assert_eq!((23, 10), (report.oxygen(), report.co2()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let (ones, zeros) = ("1".repeat(63), "0".repeat(63));
+        let wide = format!("1{}\n0{}\n1{}", zeros, ones, zeros);
+        let report: Report = wide.parse()?;
+        assert_eq!(1 << 63, report.gamma());
+        assert_eq!(u64::MAX >> 1, report.epsilon());
+        assert_eq!((u64::MAX >> 1) as u128 * (1 << 63), Day03::part1(&report));
+        assert!(format!("{}0", wide).parse::<Report>().is_err());
+
+        let err = "101\n11\n010".parse::<Report>().err().unwrap();
+        assert_eq!(Some(2), err.downcast_ref::<ParseError>().map(|e| e.line));
+        assert_eq!("00101", format!("{:?}", "00101".parse::<Line>()?));
+        assert!("".parse::<Report>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t4() -> Result<()> {
+        let report: Report = "10\n11".parse()?;
+        assert_eq!((3, 2), (report.oxygen(), report.co2()));
+        assert_eq!(6, Day03::part2(&report));
+
+        Ok(())
+    }
+}
@@ -1,18 +1,28 @@
-use aoc::{
+use crate::{
     board::{Board, Point},
-    helpers::{read_input, std_parse},
+    helpers::{csv_parse, parse_header_blocks},
+    solution::Solution,
 };
-use eyre::Result;
+use eyre::{Error, Result};
+use std::str::FromStr;
 
-fn main() -> Result<()> {
-    let input = read_input(file!())?;
-    let (plays, boards) = read_board_input(input.as_str())?;
-    println!("Part1: {}", play_bingo_v1(plays.clone(), boards.clone()));
-    println!("Part2: {}", play_bingo_v2(plays, boards));
+pub struct Day04;
+impl Solution for Day04 {
+    type Input = (Vec<u32>, Vec<Board<u32, 5, 5>>);
+    type Part1 = u32;
+    type Part2 = u32;
 
-    let _t: Vec<String> = std_parse(input)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_board_input(input)
+    }
+
+    fn part1((plays, boards): &Self::Input) -> u32 {
+        play_bingo_v1(plays.clone(), boards.clone())
+    }
 
-    Ok(())
+    fn part2((plays, boards): &Self::Input) -> u32 {
+        play_bingo_v2(plays.clone(), boards.clone())
+    }
 }
 
 fn play_bingo_v1(plays: Vec<u32>, mut boards: Vec<Board<u32, 5, 5>>) -> u32 {
@@ -55,19 +65,17 @@ fn play_bingo_v2(plays: Vec<u32>, mut boards: Vec<Board<u32, 5, 5>>) -> u32 {
     unreachable!("This should never happen")
 }
 
+struct Draws(Vec<u32>);
+impl FromStr for Draws {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Draws(csv_parse(s)?))
+    }
+}
+
 fn read_board_input(input: &str) -> Result<(Vec<u32>, Vec<Board<u32, 5, 5>>)> {
-    let (plays, rest) = input
-        .split_once("\n")
-        .expect("Should be formatted correctly");
-
-    let plays = plays
-        .split(',')
-        .map(|p| Ok(p.parse::<u32>()?))
-        .collect::<Result<_>>()?;
-    let boards = rest
-        .split("\n\n")
-        .map(|b| b.parse::<Board<u32, 5, 5>>())
-        .collect::<Result<_>>()?;
+    let (Draws(plays), boards) = parse_header_blocks(input)?;
 
     Ok((plays, boards))
 }
@@ -75,28 +83,11 @@ fn read_board_input(input: &str) -> Result<(Vec<u32>, Vec<Board<u32, 5, 5>>)> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::helpers::ParseError;
 
     #[test]
     fn t1() -> Result<()> {
-        let input = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-        22 13 17 11  0
-         8  2 23  4 24
-        21  9 14 16  7
-         6 10  3 18  5
-         1 12 20 15 19
-
-         3 15  0  2 22
-         9 18 13 17  5
-        19  8  7 25 23
-        20 11 10 24  4
-        14 21 16 12  6
-
-        14 21 17 24  4
-        10 16 15  9 19
-        18  8 23 26 20
-        22 11 13  6  5
-         2  0 12  3  7";
+        let input = include_str!("../../days/day04/example.txt");
 
         let (plays, boards) = read_board_input(input)?;
 
@@ -107,25 +98,7 @@ mod test {
 
     #[test]
     fn t2() -> Result<()> {
-        let input = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-        22 13 17 11  0
-         8  2 23  4 24
-        21  9 14 16  7
-         6 10  3 18  5
-         1 12 20 15 19
-
-         3 15  0  2 22
-         9 18 13 17  5
-        19  8  7 25 23
-        20 11 10 24  4
-        14 21 16 12  6
-
-        14 21 17 24  4
-        10 16 15  9 19
-        18  8 23 26 20
-        22 11 13  6  5
-         2  0 12  3  7";
+        let input = include_str!("../../days/day04/example.txt");
 
         let (plays, boards) = read_board_input(input)?;
 
@@ -133,4 +106,18 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn t3() {
+        let input = include_str!("../../days/day04/example.txt");
+
+        let short = input.replacen(" 8  2 23  4 24", " 8  2 23  4", 1);
+        let err = Day04::parse(&short).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().expect("a ParseError");
+        assert_eq!((4, Some(11)), (err.line, err.column));
+        assert_eq!(
+            "line 4, column 12: Row is 4 cells wide, expected 5\n  |\n4 |  8  2 23  4\n  |            ^",
+            err.to_string()
+        );
+    }
 }
@@ -0,0 +1,96 @@
+use std::str::FromStr;
+
+use crate::{
+    board::{Coord, SparseGrid},
+    helpers::std_parse,
+    solution::Solution,
+};
+use eyre::{Report, Result};
+
+pub struct Day05;
+impl Solution for Day05 {
+    type Input = Vec<LineSegment>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        std_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+fn part1(lines: &[LineSegment]) -> usize {
+    overlaps(lines.iter().flat_map(|line| line.covered_v1()))
+}
+
+fn part2(lines: &[LineSegment]) -> usize {
+    overlaps(lines.iter().flat_map(|line| line.covered_v2()))
+}
+
+fn overlaps(points: impl Iterator<Item = Coord>) -> usize {
+    let mut coverage = SparseGrid::<usize>::new();
+    for point in points {
+        *coverage.entry(point).or_default() += 1;
+    }
+    coverage.count(|n| *n >= 2)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineSegment(Coord, Coord);
+impl FromStr for LineSegment {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once(" -> ")
+            .ok_or_else(|| Report::msg(format!("Poorly constructed line segment: {}", s)))?;
+
+        Ok(LineSegment(start.parse()?, end.parse()?))
+    }
+}
+impl LineSegment {
+    fn covered_v1(&self) -> Vec<Coord> {
+        if self.0.x == self.1.x || self.0.y == self.1.y {
+            self.covered_v2()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Only horizontal, vertical and 45 degree lines are expected.
+    fn covered_v2(&self) -> Vec<Coord> {
+        let step = (self.1 - self.0).signum();
+        let len = self.0.chebyshev(&self.1) as i64;
+        (0..=len).map(|i| self.0 + step * i).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day05/example.txt");
+
+        assert_eq!(5, part1(&std_parse(input)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day05/example.txt");
+
+        assert_eq!(12, part2(&std_parse(input)?));
+
+        Ok(())
+    }
+}
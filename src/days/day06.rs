@@ -0,0 +1,95 @@
+use crate::{helpers::csv_parse, record::Record, solution::Solution};
+use eyre::Result;
+
+pub struct Day06;
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        csv_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        simulate(input.as_slice(), 80)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        simulate(input.as_slice(), 256)
+    }
+}
+
+fn simulate(input: &[usize], generations: usize) -> u64 {
+    simulate_recorded(input, generations, &mut ())
+}
+
+/// How many fish there are after `generations`, handing how many have each timer value to
+/// `recorder` after every generation.
+pub fn simulate_recorded(
+    input: &[usize],
+    generations: usize,
+    recorder: &mut impl Record<[u64; 9]>,
+) -> u64 {
+    let mut lifecycle = [0; 9];
+    for i in input {
+        lifecycle[*i] += 1;
+    }
+
+    for _ in 0..generations {
+        lifecycle = generation(lifecycle);
+        recorder.record(&lifecycle);
+    }
+    lifecycle.iter().sum()
+}
+
+fn generation(lifecycle: [u64; 9]) -> [u64; 9] {
+    let mut new_lifecycle = [0; 9];
+    for (i, x) in lifecycle.into_iter().enumerate() {
+        if i == 0 {
+            new_lifecycle[6] += x;
+            new_lifecycle[8] += x;
+        } else {
+            new_lifecycle[i - 1] += x;
+        }
+    }
+    new_lifecycle
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::record::Recorder;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day06/example.txt");
+
+        assert_eq!(5934, simulate(csv_parse(input)?.as_slice(), 80));
+        assert_eq!(vec![3, 4, 3, 1, 2], Day06::parse("3,4,3,1,2\n\n")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day06/example.txt");
+
+        assert_eq!(26984457539, simulate(csv_parse(input)?.as_slice(), 256));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let input = include_str!("../../days/day06/example.txt");
+
+        let mut timers = Recorder::new(|lifecycle: &[u64; 9]| *lifecycle);
+        assert_eq!(26, simulate_recorded(&csv_parse(input)?, 18, &mut timers));
+        assert_eq!(18, timers.frames().len());
+        // 2,3,2,0,1 after the first day.
+        assert_eq!([1, 1, 2, 1, 0, 0, 0, 0, 0], timers.frames()[0]);
+
+        Ok(())
+    }
+}
@@ -1,20 +1,23 @@
-use aoc::helpers::read_input;
+use crate::{helpers::csv_parse, solution::Solution};
 use eyre::Result;
 
-fn main() -> Result<()> {
-    let input = read_input(file!())?;
-    let t: Vec<u64> = parse_ints(input.as_str().trim())?;
-    println!("part1: {}", min_fuel(t.as_slice()));
-    println!("part2: {}", min_fuel2(t.as_slice()));
+pub struct Day07;
+impl Solution for Day07 {
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    Ok(())
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        csv_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        min_fuel(input.as_slice())
+    }
 
-fn parse_ints(input: &str) -> Result<Vec<u64>> {
-    input
-        .split(',')
-        .map(|d| Ok(d.parse()?))
-        .collect::<Result<_>>()
+    fn part2(input: &Self::Input) -> u64 {
+        min_fuel2(input.as_slice())
+    }
 }
 
 fn min_fuel(positions: &[u64]) -> u64 {
@@ -34,7 +37,7 @@ fn median(numbers: &[u64]) -> u64 {
 }
 
 fn min_fuel2(positions: &[u64]) -> u64 {
-    let mean = dbg!(mean(positions));
+    let mean = mean(positions);
     let mut last = (mean, u64::MAX);
     loop {
         let t1 = positions
@@ -50,7 +53,7 @@ fn min_fuel2(positions: &[u64]) -> u64 {
         } else if t2 < last.1 {
             last = (last.0 - 1, t2);
         } else {
-            return dbg!(last).1;
+            return last.1;
         }
     }
 }
@@ -60,30 +63,29 @@ fn fact(num: u64) -> u64 {
 }
 
 fn mean(numbers: &[u64]) -> u64 {
-    let mean = dbg!(numbers.iter().sum::<u64>() as f64 / numbers.len() as f64);
+    let mean = numbers.iter().sum::<u64>() as f64 / numbers.len() as f64;
 
     mean.round() as u64
 }
 
 #[cfg(test)]
 mod test {
-
     use super::*;
 
     #[test]
     fn t1() -> Result<()> {
-        let input = "16,1,2,0,4,2,7,1,2,14";
+        let input = include_str!("../../days/day07/example.txt");
 
-        assert_eq!(37, min_fuel(parse_ints(input)?.as_slice()));
+        assert_eq!(37, min_fuel(csv_parse(input)?.as_slice()));
 
         Ok(())
     }
 
     #[test]
     fn t2() -> Result<()> {
-        let input = "16,1,2,0,4,2,7,1,2,14";
+        let input = include_str!("../../days/day07/example.txt");
 
-        assert_eq!(168, min_fuel2(parse_ints(input)?.as_slice()));
+        assert_eq!(168, min_fuel2(csv_parse(input)?.as_slice()));
 
         Ok(())
     }
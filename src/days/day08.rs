@@ -1,16 +1,26 @@
 use std::{collections::HashSet, str::FromStr};
 
-use aoc::helpers::{read_input, std_parse};
+use crate::{helpers::std_parse, solution::Solution};
 use eyre::{Error, Result};
 use itertools::Itertools;
 
-fn main() -> Result<()> {
-    let input = read_input(file!())?;
-    let input: Vec<Display> = std_parse(input)?;
-    println!("Part1: {}", part1(input.as_slice()));
-    println!("Part2: {}", part2(input.as_slice()));
+pub struct Day08;
+impl Solution for Day08 {
+    type Input = Vec<Display>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        std_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input.as_slice())
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input.as_slice())
+    }
 }
 
 fn part1(input: &[Display]) -> usize {
@@ -31,30 +41,10 @@ fn part2(input: &[Display]) -> usize {
 }
 
 fn solve_display(display: &Display) -> usize {
-    let one = display
-        .patterns
-        .iter()
-        .filter(|s| s.len() == 2)
-        .last()
-        .unwrap();
-    let seven = display
-        .patterns
-        .iter()
-        .filter(|s| s.len() == 3)
-        .last()
-        .unwrap();
-    let four = display
-        .patterns
-        .iter()
-        .filter(|s| s.len() == 4)
-        .last()
-        .unwrap();
-    let eight = display
-        .patterns
-        .iter()
-        .filter(|s| s.len() == 7)
-        .last()
-        .unwrap();
+    let one = display.patterns.iter().rfind(|s| s.len() == 2).unwrap();
+    let seven = display.patterns.iter().rfind(|s| s.len() == 3).unwrap();
+    let four = display.patterns.iter().rfind(|s| s.len() == 4).unwrap();
+    let eight = display.patterns.iter().rfind(|s| s.len() == 7).unwrap();
     let nine = display
         .patterns
         .iter()
@@ -68,8 +58,7 @@ fn solve_display(display: &Display) -> usize {
     let zero = display
         .patterns
         .iter()
-        .filter(|s| s.len() == 6 && ![six, nine].contains(s))
-        .last()
+        .rfind(|s| s.len() == 6 && ![six, nine].contains(s))
         .unwrap();
 
     let five = display
@@ -115,12 +104,7 @@ fn solve_display(display: &Display) -> usize {
             } else if d == nine {
                 9
             } else {
-                println!("D: {:?}", d);
-                println!(
-                    "{:?}",
-                    [zero, one, two, three, four, five, six, seven, eight, nine]
-                );
-                panic!("???")
+                unreachable!("output {:?} matches none of the ten patterns", d)
             }
         })
         .join("")
@@ -128,7 +112,7 @@ fn solve_display(display: &Display) -> usize {
         .unwrap()
 }
 
-struct Display {
+pub struct Display {
     patterns: [HashSet<char>; 10],
     output: [HashSet<char>; 4],
 }
@@ -161,17 +145,7 @@ mod test {
 
     #[test]
     fn t1() -> Result<()> {
-        let input =
-            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-        edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-        fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-        fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-        aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-        fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-        dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-        bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-        egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-        gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+        let input = include_str!("../../days/day08/example.txt");
 
         let input: Vec<Display> = std_parse(input)?;
         assert_eq!(26, part1(input.as_slice()));
@@ -181,17 +155,7 @@ mod test {
 
     #[test]
     fn t2() -> Result<()> {
-        let input =
-            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-        edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-        fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-        fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-        aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-        fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-        dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-        bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-        egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-        gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+        let input = include_str!("../../days/day08/example.txt");
 
         let input: Vec<Display> = std_parse(input)?;
         assert_eq!(61229, part2(input.as_slice()));
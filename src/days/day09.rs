@@ -0,0 +1,93 @@
+use crate::{
+    board::{Grid, IntGrid, Point},
+    search::regions,
+    solution::Solution,
+};
+use eyre::Result;
+use itertools::Itertools;
+
+pub struct Day09;
+impl Solution for Day09 {
+    type Input = Grid<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse::<IntGrid>()?.0)
+    }
+
+    fn part1(board: &Self::Input) -> u32 {
+        risk_levels(board).into_iter().sum::<u32>()
+    }
+
+    fn part2(board: &Self::Input) -> u32 {
+        basins(board)
+            .into_iter()
+            .sorted()
+            .rev()
+            .take(3)
+            .product::<u32>()
+    }
+}
+
+fn risk_locations(board: &Grid<u32>) -> Vec<Point> {
+    (0..board.height())
+        .cartesian_product(0..board.width())
+        .map(|(y, x)| Point { x, y })
+        .filter(|pt| {
+            let v = board.get(pt);
+            board.neighbors(pt).all(|adj| v < board.get(&adj))
+        })
+        .collect_vec()
+}
+
+fn risk_levels(board: &Grid<u32>) -> Vec<u32> {
+    risk_locations(board)
+        .into_iter()
+        .map(|pt| *board.get(&pt) + 1)
+        .collect()
+}
+
+fn basins(board: &Grid<u32>) -> Vec<u32> {
+    regions(board, |v| *v == 9)
+        .into_iter()
+        .map(|basin| basin.size() as u32)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day09/example.txt");
+
+        let board = Day09::parse(input)?;
+
+        assert_eq!(15, risk_levels(&board).into_iter().sum::<u32>());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day09/example.txt");
+
+        let board = Day09::parse(input)?;
+
+        assert_eq!(vec![3, 9, 14, 9], basins(&board));
+
+        assert_eq!(
+            1134,
+            basins(&board)
+                .into_iter()
+                .sorted()
+                .rev()
+                .take(3)
+                .product::<u32>()
+        );
+
+        Ok(())
+    }
+}
@@ -1,19 +1,30 @@
 use core::panic;
 use std::str::FromStr;
 
-use aoc::helpers::{read_input, std_parse};
+use crate::{helpers::std_parse, solution::Solution};
 use eyre::{Error, Result};
 use itertools::Itertools;
 
-fn main() -> Result<()> {
-    let input = read_input(file!())?;
-    println!("Part1: {}", part1(std_parse(input.as_str())?));
-    println!("Part2: {}", part2(std_parse(input)?));
+pub struct Day10;
+impl Solution for Day10 {
+    type Input = Vec<Line>;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        std_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
 }
 
-struct Line(Vec<char>);
+pub struct Line(Vec<char>);
 impl FromStr for Line {
     type Err = Error;
 
@@ -91,13 +102,13 @@ impl Line {
     }
 }
 
-fn part1(input: Vec<Line>) -> u64 {
-    input.into_iter().map(|line| line.corrupt_score()).sum()
+fn part1(input: &[Line]) -> u64 {
+    input.iter().map(|line| line.corrupt_score()).sum()
 }
 
-fn part2(input: Vec<Line>) -> u64 {
+fn part2(input: &[Line]) -> u64 {
     let scores = input
-        .into_iter()
+        .iter()
         .map(|line| line.score_close())
         .filter(|s| s != &0)
         .sorted_unstable()
@@ -111,36 +122,18 @@ mod test {
 
     #[test]
     fn t1() -> Result<()> {
-        let input = "[({(<(())[]>[[{[]{<()<>>
-            [(()[<>])]({[<{<<[]>>(
-            {([(<{}[<>[]}>{[]{[(<()>
-            (((({<>}<{<{<>}{[]{[]{}
-            [[<[([]))<([[{}[[()]]]
-            [{[{({}]{}}([{[{{{}}([]
-            {<[[]]>}<{[{[{[]{()[[[]
-            [<(<(<(<{}))><([]([]()
-            <{([([[(<>()){}]>(<<{{
-            <{([{{}}[<[[[<>{}]]]>[]]";
-
-        assert_eq!(26397, part1(std_parse(input)?));
+        let input = include_str!("../../days/day10/example.txt");
+
+        assert_eq!(26397, part1(&std_parse(input)?));
 
         Ok(())
     }
 
     #[test]
     fn t2() -> Result<()> {
-        let input = "[({(<(())[]>[[{[]{<()<>>
-            [(()[<>])]({[<{<<[]>>(
-            {([(<{}[<>[]}>{[]{[(<()>
-            (((({<>}<{<{<>}{[]{[]{}
-            [[<[([]))<([[{}[[()]]]
-            [{[{({}]{}}([{[{{{}}([]
-            {<[[]]>}<{[{[{[]{()[[[]
-            [<(<(<(<{}))><([]([]()
-            <{([([[(<>()){}]>(<<{{
-            <{([{{}}[<[[[<>{}]]]>[]]";
-
-        assert_eq!(288957, part2(std_parse(input)?));
+        let input = include_str!("../../days/day10/example.txt");
+
+        assert_eq!(288957, part2(&std_parse(input)?));
 
         Ok(())
     }
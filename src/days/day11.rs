@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+
+use crate::{
+    board::{Grid, IntGrid, Point},
+    record::Record,
+    solution::Solution,
+};
+use eyre::Result;
+
+pub struct Day11;
+impl Solution for Day11 {
+    type Input = Grid<u32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse::<IntGrid>()?.0)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input.clone())
+    }
+}
+
+fn part1(input: Grid<u32>) -> u64 {
+    part1_recorded(input, &mut ())
+}
+
+/// Flashes over 100 rounds, handing the board to `recorder` after every round.
+pub fn part1_recorded(input: Grid<u32>, recorder: &mut impl Record<Grid<u32>>) -> u64 {
+    let mut p1 = Part1(input);
+    let mut flashes = 0;
+    for _r in 1..=100 {
+        flashes += p1.round();
+        recorder.record(&p1.0);
+    }
+    flashes
+}
+
+fn part2(input: Grid<u32>) -> u64 {
+    part2_recorded(input, &mut ())
+}
+
+/// The first round in which every octopus flashes, handing the board to `recorder` after
+/// every round.
+pub fn part2_recorded(input: Grid<u32>, recorder: &mut impl Record<Grid<u32>>) -> u64 {
+    let octopuses = input.len() as u64;
+    let mut p1 = Part1(input);
+    let mut i = 0;
+    loop {
+        i += 1;
+        let flashes = p1.round();
+        recorder.record(&p1.0);
+        if flashes == octopuses {
+            return i;
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Part1(Grid<u32>);
+impl Part1 {
+    fn round(&mut self) -> u64 {
+        let mut flash = 0;
+        for pt in self.0.points() {
+            self.0.incr(&pt)
+        }
+        loop {
+            let nines = self.all_flashes();
+            if nines.is_empty() {
+                self.cleanup();
+                return flash;
+            }
+            // Mark them as being used up
+            for nine in nines.iter() {
+                flash += 1;
+                self.0.set(nine, 100);
+            }
+            let mods = nines
+                .iter()
+                .flat_map(|pt| self.0.neighbors_with_diagonals(pt))
+                .collect::<Vec<_>>();
+            for m in mods {
+                self.0.incr(&m);
+            }
+        }
+    }
+
+    fn cleanup(&mut self) {
+        for pt in self.0.points() {
+            if self.0.get(&pt) > &9 {
+                self.0.set(&pt, 0);
+            }
+        }
+    }
+
+    fn all_flashes(&self) -> Vec<Point> {
+        self.0
+            .points()
+            .into_iter()
+            .filter(|pt| self.0.get(pt) > &9 && self.0.get(pt) < &100)
+            .collect()
+    }
+}
+
+impl Debug for Part1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{record::Recorder, render::Renderer};
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day11/example.txt");
+
+        assert_eq!(1656, part1(Day11::parse(input)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day11/example.txt");
+
+        assert_eq!(195, part2(Day11::parse(input)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let input = include_str!("../../days/day11/example.txt");
+
+        let renderer = Renderer::display().plain();
+        let mut recorder = Recorder::new(|grid: &Grid<u32>| renderer.render(grid));
+        assert_eq!(195, part2_recorded(Day11::parse(input)?, &mut recorder));
+        assert_eq!(195, recorder.frames().len());
+        assert_eq!(
+            "6594254334\n3856965822\n6375667284\n7252447257\n7468496589\n\
+             5278635756\n3287952832\n7993992245\n5957959665\n6394862637",
+            recorder.frames()[0]
+        );
+        assert!(recorder.frames()[194]
+            .chars()
+            .all(|c| c == '0' || c == '\n'));
+
+        Ok(())
+    }
+}
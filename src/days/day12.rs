@@ -1,23 +1,34 @@
 use std::{collections::HashMap, str::FromStr};
 
-use aoc::helpers::{read_input, std_parse};
+use crate::{helpers::std_parse, solution::Solution};
 use eyre::{Error, Result};
 use itertools::Itertools;
 
-fn main() -> Result<()> {
-    let input = read_input(file!())?;
-    println!("Part1: {}", part1(std_parse(input.as_str())?));
-    println!("Part2: {}", part2(std_parse(input)?));
+pub struct Day12;
+impl Solution for Day12 {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        std_parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
 }
 
-fn part1(input: Vec<Pair>) -> usize {
-    all_paths::<Path1>(input.as_slice()).len()
+fn part1(input: &[Pair]) -> usize {
+    all_paths::<Path1>(input).len()
 }
 
-fn part2(input: Vec<Pair>) -> usize {
-    all_paths::<Path2>(input.as_slice()).len()
+fn part2(input: &[Pair]) -> usize {
+    all_paths::<Path2>(input).len()
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -39,7 +50,7 @@ impl FromStr for Room {
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-struct Pair {
+pub struct Pair {
     start: Room,
     end: Room,
 }
@@ -182,98 +193,34 @@ mod test {
 
     #[test]
     fn t1() -> Result<()> {
-        let input1 = "start-A
-        start-b
-        A-c
-        A-b
-        b-d
-        A-end
-        b-end";
-
-        assert_eq!(10, part1(std_parse(input1)?));
-
-        let input2 = "dc-end
-        HN-start
-        start-kj
-        dc-start
-        dc-HN
-        LN-dc
-        HN-end
-        kj-sa
-        kj-HN
-        kj-dc";
-
-        assert_eq!(19, part1(std_parse(input2)?));
-
-        let input3 = "fs-end
-        he-DX
-        fs-he
-        start-DX
-        pj-DX
-        end-zg
-        zg-sl
-        zg-pj
-        pj-he
-        RW-he
-        fs-DX
-        pj-RW
-        zg-RW
-        start-pj
-        he-WI
-        zg-he
-        pj-fs
-        start-RW";
-
-        assert_eq!(226, part1(std_parse(input3)?));
+        let input1 = include_str!("../../days/day12/example.txt");
+
+        assert_eq!(10, part1(&std_parse(input1)?));
+
+        let input2 = include_str!("../../days/day12/example2.txt");
+
+        assert_eq!(19, part1(&std_parse(input2)?));
+
+        let input3 = include_str!("../../days/day12/example3.txt");
+
+        assert_eq!(226, part1(&std_parse(input3)?));
 
         Ok(())
     }
 
     #[test]
     fn t2() -> Result<()> {
-        let input1 = "start-A
-        start-b
-        A-c
-        A-b
-        b-d
-        A-end
-        b-end";
-
-        assert_eq!(36, part2(std_parse(input1)?));
-
-        let input2 = "dc-end
-        HN-start
-        start-kj
-        dc-start
-        dc-HN
-        LN-dc
-        HN-end
-        kj-sa
-        kj-HN
-        kj-dc";
-
-        assert_eq!(103, part2(std_parse(input2)?));
-
-        let input3 = "fs-end
-        he-DX
-        fs-he
-        start-DX
-        pj-DX
-        end-zg
-        zg-sl
-        zg-pj
-        pj-he
-        RW-he
-        fs-DX
-        pj-RW
-        zg-RW
-        start-pj
-        he-WI
-        zg-he
-        pj-fs
-        start-RW";
-
-        assert_eq!(3509, part2(std_parse(input3)?));
+        let input1 = include_str!("../../days/day12/example.txt");
+
+        assert_eq!(36, part2(&std_parse(input1)?));
+
+        let input2 = include_str!("../../days/day12/example2.txt");
+
+        assert_eq!(103, part2(&std_parse(input2)?));
+
+        let input3 = include_str!("../../days/day12/example3.txt");
+
+        assert_eq!(3509, part2(&std_parse(input3)?));
 
         Ok(())
     }
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use crate::{
+    board::{Grid, Point},
+    helpers::{parse_pair, Lines},
+    render::Renderer,
+    solution::Solution,
+};
+use eyre::{Error, Result};
+
+pub struct Day13;
+impl Solution for Day13 {
+    type Input = (Vec<Point>, Vec<Instruction>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (Lines(positions), Lines(instructions)) = parse_pair(input)?;
+        Ok((positions, instructions))
+    }
+
+    fn part1((positions, instructions): &Self::Input) -> usize {
+        dots(&fold(&paper(positions, instructions), instructions[0])).len()
+    }
+
+    fn part2((positions, instructions): &Self::Input) -> String {
+        to_str(&fold_all(positions, instructions))
+    }
+}
+
+/// Trimmed to the last row and column with a dot in.
+fn to_str(paper: &Grid<bool>) -> String {
+    let dots = dots(paper);
+    let width = dots.iter().map(|pt| pt.x + 1).max().unwrap_or(0);
+    let height = dots.iter().map(|pt| pt.y + 1).max().unwrap_or(0);
+    Renderer::new(|dot: &bool| if *dot { "#" } else { "." }.to_string())
+        .plain()
+        .render(&paper.crop(&Point { x: 0, y: 0 }, width, height))
+}
+
+/// Big enough for every dot and every fold line.
+fn paper(positions: &[Point], instructions: &[Instruction]) -> Grid<bool> {
+    let reach = |o: Orientation, pt: fn(&Point) -> usize| {
+        let folds = instructions.iter().filter(|i| i.orientation == o);
+        positions.iter().map(pt).chain(folds.map(|i| i.pos)).max()
+    };
+    let width = reach(Orientation::X, |pt| pt.x).map_or(0, |x| x + 1);
+    let height = reach(Orientation::Y, |pt| pt.y).map_or(0, |y| y + 1);
+
+    let mut paper = Grid::new(width, height, false);
+    for pt in positions {
+        paper.set(pt, true);
+    }
+    paper
+}
+
+fn dots(paper: &Grid<bool>) -> Vec<Point> {
+    paper
+        .points()
+        .into_iter()
+        .filter(|pt| *paper.get(pt))
+        .collect()
+}
+
+fn fold_all(positions: &[Point], instructions: &[Instruction]) -> Grid<bool> {
+    instructions
+        .iter()
+        .fold(paper(positions, instructions), |p, f| fold(&p, *f))
+}
+
+fn fold(paper: &Grid<bool>, instruction: Instruction) -> Grid<bool> {
+    let merge = |a: &bool, b: &bool| *a || *b;
+    match instruction.orientation {
+        Orientation::X => paper.fold_left(instruction.pos, merge),
+        Orientation::Y => paper.fold_up(instruction.pos, merge),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Orientation {
+    X,
+    Y,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Instruction {
+    orientation: Orientation,
+    pos: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, i) = s
+            .trim()
+            .rsplit_once(' ')
+            .ok_or_else(|| Error::msg("No space?"))?;
+        let (o, p) = i.split_once('=').ok_or_else(|| Error::msg("No Eq"))?;
+        Ok(Instruction {
+            orientation: match o {
+                "y" => Orientation::Y,
+                "x" => Orientation::X,
+                _ => return Err(Error::msg("Not x, or y")),
+            },
+            pos: p.parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day13/example.txt");
+
+        let (positions, instructions) = Day13::parse(input)?;
+        let paper = paper(&positions, &instructions);
+        assert_eq!(18, dots(&paper).len());
+        assert_eq!(17, dots(&fold(&paper, instructions[0])).len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day13/example.txt");
+
+        let (positions, instructions) = Day13::parse(input)?;
+        assert_eq!(16, dots(&fold_all(&positions, &instructions)).len());
+
+        Ok(())
+    }
+}
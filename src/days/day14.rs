@@ -0,0 +1,134 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    helpers::{parse_pair, Lines},
+    record::Record,
+    solution::Solution,
+};
+use eyre::{Error, Result};
+use itertools::Itertools;
+
+pub struct Day14;
+impl Solution for Day14 {
+    type Input = (String, HashMap<(char, char), char>);
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (template, Lines(rules)): (String, Lines<Production>) = parse_pair(input)?;
+        let rule_map = rules
+            .into_iter()
+            .map(|rule| (rule.input, rule.out))
+            .collect::<HashMap<_, _>>();
+
+        Ok((template, rule_map))
+    }
+
+    fn part1((template, rules): &Self::Input) -> u128 {
+        part1(template, rules)
+    }
+
+    fn part2((template, rules): &Self::Input) -> u128 {
+        part2(template, rules)
+    }
+}
+
+fn part1(template: &str, rules: &HashMap<(char, char), char>) -> u128 {
+    polymerize(template, rules, 10, &mut ())
+}
+
+fn part2(template: &str, rules: &HashMap<(char, char), char>) -> u128 {
+    polymerize(template, rules, 40, &mut ())
+}
+
+/// Runs `steps` rounds of insertion, handing the element counts to `recorder` after each.
+/// With fewer than two distinct elements the most and least common are the same, so 0.
+pub fn polymerize(
+    template: &str,
+    rules: &HashMap<(char, char), char>,
+    steps: usize,
+    recorder: &mut impl Record<HashMap<char, u128>>,
+) -> u128 {
+    let mut counts: HashMap<char, u128> = template
+        .chars()
+        .counts()
+        .into_iter()
+        .map(|(a, b)| (a, b as u128))
+        .collect();
+    let mut pair_counts: HashMap<(char, char), u128> = Default::default();
+    for (a, b) in template.chars().tuple_windows() {
+        *pair_counts.entry((a, b)).or_default() += 1;
+    }
+    for _r in 0..steps {
+        let mut new = HashMap::new();
+        for ((a, b), score) in pair_counts {
+            let rule = rules[&(a, b)];
+            *counts.entry(rule).or_default() += score;
+            *new.entry((a, rule)).or_default() += score;
+            *new.entry((rule, b)).or_default() += score;
+        }
+        pair_counts = new;
+        recorder.record(&counts);
+    }
+    match counts.iter().minmax_by_key(|(_, s)| **s) {
+        itertools::MinMaxResult::NoElements | itertools::MinMaxResult::OneElement(_) => 0,
+        itertools::MinMaxResult::MinMax((_, min), (_, max)) => max - min,
+    }
+}
+
+struct Production {
+    input: (char, char),
+    out: char,
+}
+
+impl FromStr for Production {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, out) = s
+            .split_once(" -> ")
+            .ok_or_else(|| Error::msg("no -> found"))?;
+
+        Ok(Production {
+            input: input
+                .chars()
+                .collect_tuple()
+                .ok_or_else(|| Error::msg(format!("Not a pair: {}", input)))?,
+            out: out
+                .chars()
+                .exactly_one()
+                .map_err(|_| Error::msg(format!("Not a single element: {}", out)))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::record::Recorder;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let input = include_str!("../../days/day14/example.txt");
+
+        let (template, rule_map) = Day14::parse(input)?;
+        assert_eq!(1588, part1(&template, &rule_map));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let input = include_str!("../../days/day14/example.txt");
+
+        let (template, rule_map) = Day14::parse(input)?;
+        let mut lengths = Recorder::new(|counts: &HashMap<char, u128>| counts.values().sum());
+        assert_eq!(1588, polymerize(&template, &rule_map, 10, &mut lengths));
+        assert_eq!([7, 13, 25, 49], lengths.frames()[..4]);
+        assert_eq!(Some(&3073), lengths.frames().last());
+        assert_eq!(0, polymerize("N", &rule_map, 10, &mut ()));
+        assert_eq!(0, polymerize("", &rule_map, 10, &mut ()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::solution::{solve, Runner, Solution};
+use eyre::{Error, Result};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+pub struct Day {
+    pub day: u8,
+    pub run: Runner,
+}
+
+pub const DAYS: &[Day] = &[
+    entry::<day01::Day01>(1),
+    entry::<day02::Day02>(2),
+    entry::<day03::Day03>(3),
+    entry::<day04::Day04>(4),
+    entry::<day05::Day05>(5),
+    entry::<day06::Day06>(6),
+    entry::<day07::Day07>(7),
+    entry::<day08::Day08>(8),
+    entry::<day09::Day09>(9),
+    entry::<day10::Day10>(10),
+    entry::<day11::Day11>(11),
+    entry::<day12::Day12>(12),
+    entry::<day13::Day13>(13),
+    entry::<day14::Day14>(14),
+];
+
+const fn entry<S: Solution>(day: u8) -> Day {
+    Day {
+        day,
+        run: solve::<S>,
+    }
+}
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Resolves a day selector: `all`, a single day (`5`), or a range (`3..7`, `3..=7`).
+pub fn select(spec: &str) -> Result<Vec<&'static Day>> {
+    let spec = spec.trim();
+    if spec == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+
+    let (start, end) = if let Some((start, end)) = spec.split_once("..=") {
+        (start.parse::<u8>()?, end.parse::<u8>()?)
+    } else if let Some((start, end)) = spec.split_once("..") {
+        let end = end.parse::<u8>()?;
+        (
+            start.parse::<u8>()?,
+            end.checked_sub(1)
+                .ok_or_else(|| Error::msg(format!("Empty range: {}", spec)))?,
+        )
+    } else {
+        let day = spec.parse::<u8>()?;
+        return get(day)
+            .map(|d| vec![d])
+            .ok_or_else(|| Error::msg(format!("No solution registered for day {}", day)));
+    };
+
+    let days = DAYS
+        .iter()
+        .filter(|d| (start..=end).contains(&d.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(Error::msg(format!("No solutions registered in {}", spec)));
+    }
+    Ok(days)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        assert_eq!(DAYS.len(), select("all")?.len());
+        assert_eq!(
+            vec![5],
+            select("5")?.iter().map(|d| d.day).collect::<Vec<_>>()
+        );
+        assert!(select("26").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let days = |spec| -> Result<Vec<u8>> { Ok(select(spec)?.iter().map(|d| d.day).collect()) };
+
+        assert_eq!(vec![3, 4, 5, 6], days("3..7")?);
+        assert_eq!(vec![3, 4, 5, 6, 7], days("3..=7")?);
+        assert!(days("20..25").is_err());
+
+        Ok(())
+    }
+}
@@ -1,31 +1,626 @@
-use eyre::{Error, Result};
+use eyre::{Error, Report, Result, WrapErr};
+use itertools::Itertools;
 use std::{
+    ffi::OsString,
+    fmt::Display,
     fs::File,
-    io::{BufReader, Read},
+    io::{BufRead, BufReader, Read},
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
-pub fn std_parse<R: AsRef<str>, T: FromStr>(input: R) -> Result<Vec<T>, T::Err> {
+/// Parses each trimmed line of `input` as a `T`, reporting the first failure as a
+/// [`ParseError`] that points at the offending line.
+pub fn std_parse<R: AsRef<str>, T>(input: R) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Report>,
+{
     input
         .as_ref()
         .lines()
-        .map(|l| l.trim())
-        .map(|i| i.parse::<T>())
-        .collect::<Result<Vec<_>, _>>()
-}
-
-pub fn read_input(filename: &str) -> Result<String> {
-    let file = File::open(format!(
-        "days/{}.txt",
-        filename
-            .rsplit_once('/')
-            .ok_or_else(|| Error::msg("Filename weirdness?"))?
-            .1
-            .split_once('.')
-            .ok_or_else(|| Error::msg("Filename weirdness?"))?
-            .0
-    ))?;
+        .enumerate()
+        .map(|(i, line)| {
+            let trimmed = line.trim();
+            trimmed.parse::<T>().map_err(|e| {
+                let offset = line.chars().count() - line.trim_start().chars().count();
+                ParseError::new(i + 1, line, e.into()).shift(offset).into()
+            })
+        })
+        .collect()
+}
+
+/// Splits input on a separator and parses every token, trimming whitespace around each.
+/// Blank lines have no tokens, whatever the separator.
+///
+/// Failures are [`ParseError`]s pointing at the offending token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delimiter<'a> {
+    /// `None` splits on runs of whitespace.
+    separator: Option<&'a str>,
+    skip_empty: bool,
+}
+
+impl<'a> Delimiter<'a> {
+    /// Fails if `separator` is empty, as it would split between every character.
+    pub fn new(separator: &'a str) -> Result<Delimiter<'a>> {
+        if separator.is_empty() {
+            return Err(Error::msg("Empty separator"));
+        }
+        Ok(Delimiter {
+            separator: Some(separator),
+            skip_empty: false,
+        })
+    }
+
+    pub fn whitespace() -> Delimiter<'a> {
+        Delimiter {
+            separator: None,
+            skip_empty: false,
+        }
+    }
+
+    /// Drop empty tokens (e.g. from `1,,2` or a trailing `,`) instead of failing on them.
+    pub fn skip_empty(self) -> Delimiter<'a> {
+        Delimiter {
+            skip_empty: true,
+            ..self
+        }
+    }
+
+    /// Parses every token on every line into one flat list.
+    pub fn parse<T>(&self, input: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Report>,
+    {
+        Ok(self.parse_lines(input)?.into_iter().flatten().collect())
+    }
+
+    /// Parses each line into its own list of tokens, so a blank line is an empty list.
+    pub fn parse_lines<T>(&self, input: &str) -> Result<Vec<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Into<Report>,
+    {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| self.parse_line(i + 1, line))
+            .collect()
+    }
+
+    fn parse_line<T>(&self, number: usize, line: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Report>,
+    {
+        let mut values = Vec::new();
+        if line.trim().is_empty() {
+            return Ok(values);
+        }
+        for (byte, token) in self.tokens(line) {
+            let leading = token.len() - token.trim_start().len();
+            let token = token.trim();
+            let column = line[..byte + leading].chars().count();
+            let error = |e: Report| -> Report {
+                ParseError::new(number, line, e)
+                    .token(column, token.chars().count())
+                    .into()
+            };
+            if token.is_empty() {
+                if self.skip_empty {
+                    continue;
+                }
+                return Err(error(Error::msg("Empty value")));
+            }
+            values.push(token.parse::<T>().map_err(|e| error(e.into()))?);
+        }
+        Ok(values)
+    }
+
+    /// Tokens of `line` with their byte offsets.
+    fn tokens<'l>(&self, line: &'l str) -> Vec<(usize, &'l str)> {
+        match self.separator {
+            Some(separator) => {
+                let mut offset = 0;
+                line.split(separator)
+                    .map(|token| {
+                        let start = offset;
+                        offset += token.len() + separator.len();
+                        (start, token)
+                    })
+                    .collect()
+            }
+            None => line
+                .split_whitespace()
+                .map(|token| (token.as_ptr() as usize - line.as_ptr() as usize, token))
+                .collect(),
+        }
+    }
+}
+
+/// Comma-separated values across any number of lines.
+pub fn csv_parse<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Report>,
+{
+    Delimiter::new(",")?.parse(input)
+}
+
+/// A failure at a known position within the text handed to a parser.
+///
+/// `FromStr` impls can return this (directly or as the root of an eyre report) so that
+/// [`std_parse`] can put a caret under the problem instead of the whole line.
+#[derive(Debug)]
+pub struct ColumnError {
+    /// 0-based char offset into the parsed text.
+    pub column: usize,
+    pub message: String,
+}
+
+impl ColumnError {
+    pub fn new<M: Display>(column: usize, message: M) -> ColumnError {
+        ColumnError {
+            column,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ColumnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.column + 1, self.message)
+    }
+}
+
+impl std::error::Error for ColumnError {}
+
+/// A line of input that failed to parse, with enough context to show where.
+#[derive(Debug)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    pub text: String,
+    /// 0-based char offset into `text`, when known.
+    pub column: Option<usize>,
+    pub message: String,
+    /// Chars of `text` to underline, as `(start, len)`.
+    span: (usize, usize),
+}
+
+impl ParseError {
+    pub fn new(line: usize, text: &str, source: Report) -> ParseError {
+        let text = text.trim_end().to_string();
+        let (column, message, span) = match source.downcast_ref::<ColumnError>() {
+            Some(e) => (Some(e.column), e.message.clone(), (e.column, 1)),
+            None => (None, source.to_string(), (0, text.chars().count())),
+        };
+        ParseError {
+            line,
+            column,
+            message,
+            span,
+            text,
+        }
+    }
+
+    /// Moves the reported position right by `offset` chars, for parsers that were only
+    /// given the tail of the line.
+    fn shift(mut self, offset: usize) -> ParseError {
+        self.column = self.column.map(|c| c + offset);
+        self.span = match self.column {
+            Some(c) => (c, 1),
+            None => (self.span.0 + offset, self.span.1.saturating_sub(offset)),
+        };
+        self
+    }
+
+    /// Narrows the error to a `len`-char token starting at `start`, for parsers that
+    /// were only given that token.
+    fn token(mut self, start: usize, len: usize) -> ParseError {
+        self.span = match self.column {
+            Some(c) => (start + c, 1),
+            None => (start, len),
+        };
+        self.column = Some(self.span.0);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        match self.column {
+            Some(column) => writeln!(
+                f,
+                "line {}, column {}: {}",
+                self.line,
+                column + 1,
+                self.message
+            )?,
+            None => writeln!(f, "line {}: {}", self.line, self.message)?,
+        }
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.span.0),
+            "^".repeat(self.span.1.max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Newline-separated values, so a whole section can be parsed with `FromStr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lines<T>(pub Vec<T>);
+
+impl<T> FromStr for Lines<T>
+where
+    T: FromStr,
+    T::Err: Into<Report>,
+{
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Lines(std_parse(s)?))
+    }
+}
+
+/// A run of non-blank lines from a blank-line-separated input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// 1-based line number of the section's first line.
+    pub line: usize,
+    lines: Vec<&'a str>,
+}
+
+impl Section<'_> {
+    /// The section's lines with trailing whitespace (and any `\r`) removed.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Parses the section, adjusting any [`ParseError`] to count lines from the start
+    /// of the whole input.
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Into<Report>,
+    {
+        self.text().parse::<T>().map_err(|e| {
+            let mut e: Report = e.into();
+            if let Some(parse_error) = e.downcast_mut::<ParseError>() {
+                parse_error.line += self.line - 1;
+            }
+            e.wrap_err(format!("In section starting at line {}", self.line))
+        })
+    }
+}
+
+/// Splits input into sections separated by blank (or whitespace-only) lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut blank = true;
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank = true;
+            continue;
+        }
+        if blank {
+            sections.push(Section {
+                line: i + 1,
+                lines: Vec::new(),
+            });
+            blank = false;
+        }
+        if let Some(section) = sections.last_mut() {
+            section.lines.push(line);
+        }
+    }
+    sections
+}
+
+/// Parses input made of exactly two differently shaped sections.
+pub fn parse_pair<A, B>(input: &str) -> Result<(A, B)>
+where
+    A: FromStr,
+    A::Err: Into<Report>,
+    B: FromStr,
+    B::Err: Into<Report>,
+{
+    match sections(input).as_slice() {
+        [a, b] => Ok((a.parse()?, b.parse()?)),
+        found => Err(Error::msg(format!(
+            "Expected 2 blank-line-separated sections, found {}",
+            found.len()
+        ))),
+    }
+}
+
+/// Parses a header section followed by any number of same-shaped blocks.
+pub fn parse_header_blocks<H, B>(input: &str) -> Result<(H, Vec<B>)>
+where
+    H: FromStr,
+    H::Err: Into<Report>,
+    B: FromStr,
+    B::Err: Into<Report>,
+{
+    match sections(input).split_first() {
+        Some((header, blocks)) => Ok((
+            header.parse()?,
+            blocks.iter().map(|b| b.parse()).collect::<Result<_>>()?,
+        )),
+        None => Err(Error::msg("Expected a header section, found empty input")),
+    }
+}
+
+/// Environment variable naming the directory that holds the `dayNN/` input folders.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// The input used when no name is given: the puzzle input proper, as opposed to
+/// the examples or someone else's account.
+pub const DEFAULT_INPUT: &str = "real";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read everything from standard input (`-` on the command line).
+    Stdin,
+    /// An explicit file path.
+    Path(PathBuf),
+    /// `dayNN/<name>.txt` in the inputs directory (see [`input_dirs`]).
+    Day(u8, String),
+}
+
+impl FromStr for InputSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "" => return Err(Error::msg("Empty input path")),
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.into()),
+        })
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut buf)
+                    .wrap_err("Couldn't read input from stdin")?;
+                Ok(buf)
+            }
+            InputSource::Path(path) => read_file(path),
+            InputSource::Day(day, name) => read_file(find_named(*day, name)?),
+        }
+    }
+
+    /// A buffered reader over the input, for inputs too big to read into memory at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        let path = match self {
+            InputSource::Stdin => return Ok(Box::new(std::io::stdin().lock())),
+            InputSource::Path(path) => path.clone(),
+            InputSource::Day(day, name) => find_named(*day, name)?,
+        };
+        let file =
+            File::open(&path).wrap_err_with(|| format!("Couldn't open {}", path.display()))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Directories searched for named inputs, in order.
+///
+/// If `AOC_INPUTS` is set it is the only candidate; otherwise `days/` relative to the
+/// current directory is tried, then `days/` in the crate root.
+pub fn input_dirs() -> Vec<PathBuf> {
+    candidate_dirs(std::env::var_os(INPUTS_ENV))
+}
+
+fn candidate_dirs(env: Option<OsString>) -> Vec<PathBuf> {
+    match env {
+        Some(dir) => vec![dir.into()],
+        None => vec![
+            PathBuf::from("days"),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("days"),
+        ],
+    }
+}
+
+fn find_named(day: u8, name: &str) -> Result<PathBuf> {
+    let tried = input_dirs()
+        .into_iter()
+        .flat_map(|dir| {
+            let named = dir
+                .join(format!("day{:02}", day))
+                .join(format!("{}.txt", name));
+            // Flat `dayNN.txt` files predate named inputs and hold the real input.
+            let flat = (name == DEFAULT_INPUT).then(|| dir.join(format!("day{:02}.txt", day)));
+            [Some(named), flat].into_iter().flatten()
+        })
+        .collect::<Vec<_>>();
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(Error::msg(format!(
+            "No input file found, tried: {}",
+            tried.iter().map(|p| p.display()).join(", ")
+        ))),
+    }
+}
+
+/// Names of every input available for a day, e.g. `["alice", "example", "real"]`.
+pub fn input_names(day: u8) -> Vec<String> {
+    names_in(input_dirs(), day)
+}
+
+fn names_in(dirs: Vec<PathBuf>, day: u8) -> Vec<String> {
+    dirs.into_iter()
+        .flat_map(|dir| {
+            let flat = dir.join(format!("day{:02}.txt", day)).is_file();
+            let named = std::fs::read_dir(dir.join(format!("day{:02}", day)))
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    match path.extension() {
+                        Some(ext) if ext == "txt" => Some(path.file_stem()?.to_str()?.to_string()),
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<_>>();
+            named
+                .into_iter()
+                .chain(flat.then(|| DEFAULT_INPUT.to_string()))
+        })
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+fn read_file<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).wrap_err_with(|| format!("Couldn't open {}", path.display()))?;
     let mut buf = Vec::new();
-    BufReader::new(file).read_to_end(&mut buf)?;
-    Ok(String::from_utf8(buf)?)
+    BufReader::new(file)
+        .read_to_end(&mut buf)
+        .wrap_err_with(|| format!("Couldn't read {}", path.display()))?;
+    String::from_utf8(buf).wrap_err_with(|| format!("{} is not UTF-8", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() -> Result<()> {
+        assert_eq!(InputSource::Stdin, "-".parse()?);
+        assert_eq!(
+            InputSource::Path("days/example.txt".into()),
+            "days/example.txt".parse()?
+        );
+        assert!("".parse::<InputSource>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        assert_eq!(
+            vec![PathBuf::from("/tmp/inputs")],
+            candidate_dirs(Some("/tmp/inputs".into()))
+        );
+        assert_eq!(PathBuf::from("days"), candidate_dirs(None)[0]);
+        assert!(names_in(candidate_dirs(None), 1).contains(&"example".to_string()));
+
+        let err = InputSource::Path("no/such/file.txt".into())
+            .read()
+            .unwrap_err();
+        assert!(err.to_string().contains("no/such/file.txt"));
+        let mut example = String::new();
+        InputSource::Path(Path::new(env!("CARGO_MANIFEST_DIR")).join("days/day01/example.txt"))
+            .open()?
+            .read_to_string(&mut example)?;
+        assert_eq!(include_str!("../days/day01/example.txt"), example);
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let err = std_parse::<_, u32>("12\n  3x4\n5").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().expect("a ParseError");
+        assert_eq!((2, None), (err.line, err.column));
+        assert_eq!(
+            "line 2: invalid digit found in string\n  |\n2 |   3x4\n  |   ^^^",
+            err.to_string()
+        );
+
+        #[derive(Debug)]
+        struct Bit;
+        impl FromStr for Bit {
+            type Err = ColumnError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.find(|c| c != '0' && c != '1') {
+                    Some(i) => Err(ColumnError::new(i, "not a bit")),
+                    None => Ok(Bit),
+                }
+            }
+        }
+        let err = std_parse::<_, Bit>("0101\n 0120").unwrap_err();
+        assert_eq!(
+            "line 2, column 4: not a bit\n  |\n2 |  0120\n  |    ^",
+            err.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn t4() -> Result<()> {
+        let input = "1,2,3 \r\n\r\n4\r\n5\r\n  \r\n6\n7\n\n";
+        let found = sections(input);
+        assert_eq!(
+            vec![1, 3, 6],
+            found.iter().map(|s| s.line).collect::<Vec<_>>()
+        );
+        assert_eq!("4\n5", found[1].text());
+
+        let (header, blocks): (String, Vec<Lines<u32>>) = parse_header_blocks(input)?;
+        assert_eq!("1,2,3", header);
+        assert_eq!(vec![Lines(vec![4, 5]), Lines(vec![6, 7])], blocks);
+
+        assert!(parse_pair::<String, Lines<u32>>(input).is_err());
+        let err = parse_pair::<String, Lines<u32>>("a\n\n1\nb").unwrap_err();
+        assert_eq!(Some(4), err.downcast_ref::<ParseError>().map(|e| e.line));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t5() -> Result<()> {
+        assert_eq!(vec![3, 4, 3, 1, 2], csv_parse::<u32>("3, 4,3 ,1,2\n")?);
+        assert_eq!(vec![3, 4, 3], csv_parse::<u32>("\n3,4\n  \n3\n\n")?);
+        assert_eq!(
+            vec![vec![1, 2], vec![], vec![3]],
+            Delimiter::new(";")?
+                .skip_empty()
+                .parse_lines::<u32>("1;;2;\n\n3")?
+        );
+        for delimiter in [Delimiter::new(",")?, Delimiter::whitespace()] {
+            assert_eq!(
+                vec![vec![1], vec![], vec![3]],
+                delimiter.parse_lines::<u32>("1\n \n3")?
+            );
+        }
+        assert!(Delimiter::new("").is_err());
+        assert_eq!(
+            vec![vec![22, 13, 17], vec![8, 2, 23]],
+            Delimiter::whitespace().parse_lines::<u32>("22 13 17\n 8  2 23")?
+        );
+
+        let err = csv_parse::<u32>("1,2\n3, 4x ,5").unwrap_err();
+        assert_eq!(
+            "line 2, column 4: invalid digit found in string\n  |\n2 | 3, 4x ,5\n  |    ^^",
+            err.to_string()
+        );
+        let err = csv_parse::<u32>("1,,2").unwrap_err();
+        assert_eq!(
+            Some(2),
+            err.downcast_ref::<ParseError>().and_then(|e| e.column)
+        );
+
+        Ok(())
+    }
 }
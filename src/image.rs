@@ -0,0 +1,293 @@
+use std::{path::Path, time::Duration};
+
+use eyre::{Context, Error, Result};
+
+use crate::board::{Cells, Coord, Point, SparseGrid};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// Shades from black at 0 to white at `max`, for boards of small numbers.
+pub fn gray(value: u32, max: u32) -> Rgb {
+    let v = (value.min(max) as u64 * 255 / max.max(1) as u64) as u8;
+    [v, v, v]
+}
+
+/// An RGB picture, for looking at boards when a terminal dump won't do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// One pixel per cell, colored by `palette`.
+    pub fn from_cells<C: Cells>(cells: &C, palette: impl Fn(&C::Cell) -> Rgb) -> Image {
+        let mut image = Image::new(cells.width(), cells.height(), BLACK);
+        for y in 0..cells.height() {
+            for x in 0..cells.width() {
+                let p = Point { x, y };
+                image.set(&p, palette(cells.get(&p)));
+            }
+        }
+        image
+    }
+
+    /// The bounding box of `points`, with them in `fg` on `bg`.
+    pub fn from_points<P: Into<Coord>>(
+        points: impl IntoIterator<Item = P>,
+        fg: Rgb,
+        bg: Rgb,
+    ) -> Image {
+        let points: SparseGrid<()> = points.into_iter().map(|p| (p.into(), ())).collect();
+        let Some((lo, hi)) = points.bounds() else {
+            return Image::new(0, 0, bg);
+        };
+        let mut image = Image::new((hi.x - lo.x + 1) as usize, (hi.y - lo.y + 1) as usize, bg);
+        for (p, _) in points.iter() {
+            image.set(&Point::try_from(*p - lo).expect("inside the bounds"), fg);
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, p: &Point) -> Rgb {
+        self.pixels[p.y * self.width + p.x]
+    }
+
+    pub fn set(&mut self, p: &Point, color: Rgb) {
+        self.pixels[p.y * self.width + p.x] = color;
+    }
+
+    /// Every pixel blown up to a `factor` by `factor` square.
+    pub fn scale(&self, factor: usize) -> Image {
+        let mut image = Image::new(self.width * factor, self.height * factor, BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                let from = Point {
+                    x: x / factor,
+                    y: y / factor,
+                };
+                image.set(&Point { x, y }, self.get(&from));
+            }
+        }
+        image
+    }
+
+    /// Binary PPM (P6), which most image viewers open.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    /// An 8-bit RGB PNG. The image data is stored without compression, which keeps this
+    /// dependency-free at the cost of file size.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut out = PNG_SIGNATURE.to_vec();
+        png_chunk(&mut out, b"IHDR", &self.png_header());
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&self.scanlines()));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Writes a `.ppm` or `.png`, going by the extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            Some("png") => self.to_png(),
+            _ => {
+                return Err(Error::msg(format!(
+                    "Don't know how to write {:?}, use .ppm or .png",
+                    path
+                )))
+            }
+        };
+        std::fs::write(path, bytes).wrap_err_with(|| format!("Writing {:?}", path))
+    }
+
+    fn png_header(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, truecolor, default compression/filter, no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        header
+    }
+
+    /// Rows of pixels, each led by a "no filter" byte.
+    fn scanlines(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)) {
+            data.push(0);
+            data.extend(row.iter().flatten());
+        }
+        data
+    }
+}
+
+/// An animated PNG showing each of `frames` for `delay`, looping forever. Frames must all
+/// be the same size.
+pub fn apng(frames: &[Image], delay: Duration) -> Result<Vec<u8>> {
+    let first = frames
+        .first()
+        .ok_or_else(|| Error::msg("No frames to animate"))?;
+    let size = (first.width, first.height);
+    if let Some(i) = frames.iter().position(|f| (f.width, f.height) != size) {
+        return Err(Error::msg(format!(
+            "Frame {} is {}x{}, the first is {}x{}",
+            i, frames[i].width, frames[i].height, size.0, size.1
+        )));
+    }
+    let delay_ms = delay.as_millis().min(u16::MAX as u128) as u16;
+
+    let mut out = PNG_SIGNATURE.to_vec();
+    png_chunk(&mut out, b"IHDR", &first.png_header());
+    let mut animation = (frames.len() as u32).to_be_bytes().to_vec();
+    animation.extend(0u32.to_be_bytes());
+    png_chunk(&mut out, b"acTL", &animation);
+
+    // fcTL and fdAT chunks share one sequence.
+    let mut sequence = 0u32..;
+    for (i, frame) in frames.iter().enumerate() {
+        let mut control = sequence.next().unwrap().to_be_bytes().to_vec();
+        for n in [size.0 as u32, size.1 as u32, 0, 0] {
+            control.extend(n.to_be_bytes());
+        }
+        control.extend(delay_ms.to_be_bytes());
+        control.extend(1000u16.to_be_bytes());
+        // Leave the frame in place and draw the next over it without blending.
+        control.extend([0, 0]);
+        png_chunk(&mut out, b"fcTL", &control);
+
+        let data = zlib_stored(&frame.scanlines());
+        if i == 0 {
+            png_chunk(&mut out, b"IDAT", &data);
+        } else {
+            let mut frame_data = sequence.next().unwrap().to_be_bytes().to_vec();
+            frame_data.extend(data);
+            png_chunk(&mut out, b"fdAT", &frame_data);
+        }
+    }
+    png_chunk(&mut out, b"IEND", &[]);
+    Ok(out)
+}
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |c, _| {
+            if c & 1 == 1 {
+                (c >> 1) ^ 0xedb8_8320
+            } else {
+                c >> 1
+            }
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::board::IntGrid;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let grid = "09\n90".parse::<IntGrid>()?.0;
+        let image = Image::from_cells(&grid, |v| gray(*v, 9));
+        assert_eq!(WHITE, image.get(&Point { x: 1, y: 0 }));
+
+        let big = image.scale(2);
+        assert_eq!((4, 4), (big.width(), big.height()));
+        assert_eq!(WHITE, big.get(&Point { x: 3, y: 1 }));
+        assert_eq!(BLACK, big.get(&Point { x: 3, y: 3 }));
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(11 + 4 * 3, ppm.len());
+
+        let dots = Image::from_points([Coord::new(-1, 5), Coord::new(1, 6)], WHITE, BLACK);
+        assert_eq!((3, 2), (dots.width(), dots.height()));
+        assert_eq!(WHITE, dots.get(&Point { x: 2, y: 1 }));
+        assert_eq!(BLACK, dots.get(&Point { x: 1, y: 0 }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() {
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+        assert_eq!(WHITE, gray(20_000_000, 20_000_000));
+        assert_eq!([127; 3], gray(u32::MAX / 2, u32::MAX));
+
+        let zlib = zlib_stored(&vec![7; 70_000]);
+        assert_eq!(2 + 5 + 65535 + 5 + 4465 + 4, zlib.len());
+        assert_eq!([0, 0xff, 0xff, 0, 0], zlib[2..7]);
+        assert_eq!(1, zlib[2 + 5 + 65535]);
+
+        let png = Image::new(3, 2, [1, 2, 3]).to_png();
+        assert_eq!(PNG_SIGNATURE, png[..8]);
+        assert_eq!(b"IHDR", &png[12..16]);
+        assert_eq!([0, 0, 0, 3, 0, 0, 0, 2, 8, 2], png[16..26]);
+        assert_eq!(
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82],
+            png[png.len() - 12..]
+        );
+    }
+}
@@ -1,2 +1,9 @@
+pub mod answers;
 pub mod board;
+pub mod days;
 pub mod helpers;
+pub mod image;
+pub mod record;
+pub mod render;
+pub mod search;
+pub mod solution;
@@ -0,0 +1,294 @@
+use aoc::{
+    answers::{answers_path, AnswerFile},
+    days::{self, day01, Day},
+    helpers::{input_names, InputSource, DEFAULT_INPUT},
+    solution::{Answers, Run, Samples},
+};
+use eyre::{Error, Result};
+use std::time::Duration;
+
+const USAGE: &str = "usage:
+    aoc run [-i INPUT | --name NAME] <DAYS>...
+    aoc bench [-i INPUT | --name NAME] [-n ITERATIONS] <DAYS>...
+    aoc verify [--record] [--name NAME] [DAYS]...
+    aoc stream 1 [-i INPUT | --name NAME] [--width N] [--lenient]
+
+DAYS is `all`, a single day (`5`) or a range (`3..7`, `3..=7`).
+INPUT is a file path, or `-` for stdin, and needs exactly one day.
+NAME picks dayNN/NAME.txt (default `real`), or `all` for every input of a day.
+Inputs are read from $AOC_INPUTS, falling back to days/, and answers from
+answers.toml alongside them.
+stream reads day 1 a line at a time, so INPUT can be bigger than memory. It counts
+rising readings and rising sums of N-reading windows (default 3); --lenient skips and
+counts lines that aren't depths instead of failing.";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_WIDTH: usize = 3;
+const ALL_INPUTS: &str = "all";
+
+struct Args {
+    command: String,
+    days: Vec<&'static Day>,
+    input: Option<InputSource>,
+    name: String,
+    iterations: usize,
+    record: bool,
+    width: usize,
+    lenient: bool,
+}
+
+/// One day run against one input.
+struct Job {
+    day: &'static Day,
+    name: String,
+    source: InputSource,
+}
+
+impl Job {
+    fn label(&self) -> String {
+        match self.name.as_str() {
+            DEFAULT_INPUT => format!("{:02}", self.day.day),
+            name => format!("{:02} ({})", self.day.day, name),
+        }
+    }
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Args> {
+        let command = args.next().ok_or_else(|| Error::msg(USAGE))?;
+        let mut parsed = Args {
+            command,
+            days: Vec::new(),
+            input: None,
+            name: DEFAULT_INPUT.to_string(),
+            iterations: DEFAULT_ITERATIONS,
+            record: false,
+            width: DEFAULT_WIDTH,
+            lenient: false,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| Error::msg(format!("{} needs a value\n\n{}", arg, USAGE)))
+            };
+            match arg.as_str() {
+                "-i" | "--input" => parsed.input = Some(value()?.parse()?),
+                "--name" => parsed.name = value()?,
+                "-n" | "--iterations" => parsed.iterations = value()?.parse()?,
+                "--record" => parsed.record = true,
+                "--width" => parsed.width = value()?.parse()?,
+                "--lenient" => parsed.lenient = true,
+                spec => parsed.days.extend(days::select(spec)?),
+            }
+        }
+        if parsed.input.is_some() && parsed.days.len() != 1 {
+            return Err(Error::msg("--input needs exactly one day"));
+        }
+        Ok(parsed)
+    }
+
+    fn jobs(&self) -> Vec<Job> {
+        let days = if self.days.is_empty() && self.command == "verify" {
+            days::DAYS.iter().collect()
+        } else {
+            self.days.clone()
+        };
+        days.into_iter()
+            .flat_map(|day| {
+                if let Some(source) = &self.input {
+                    let name = match source {
+                        InputSource::Stdin => "stdin",
+                        _ => "input",
+                    };
+                    return vec![Job {
+                        day,
+                        name: name.to_string(),
+                        source: source.clone(),
+                    }];
+                }
+                let names = match self.name.as_str() {
+                    ALL_INPUTS => input_names(day.day),
+                    name => vec![name.to_string()],
+                };
+                names
+                    .into_iter()
+                    .map(|name| Job {
+                        day,
+                        source: InputSource::Day(day.day, name.clone()),
+                        name,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse(std::env::args().skip(1))?;
+    match args.command.as_str() {
+        "verify" if args.input.is_some() => Err(Error::msg("verify only uses named inputs")),
+        "verify" => verify(&args),
+        _ if args.days.is_empty() => Err(Error::msg(USAGE)),
+        "run" => run(&args),
+        "bench" => bench(&args),
+        "stream" => stream(&args),
+        _ => Err(Error::msg(USAGE)),
+    }
+}
+
+fn run(args: &Args) -> Result<()> {
+    let mut runs = Vec::new();
+    for job in args.jobs() {
+        let run = (job.day.run)(job.source.read()?.as_str(), 1)?;
+        print_day(&job, &run.answers);
+        runs.push((job, run));
+    }
+
+    println!();
+    println!(
+        "{:<16} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for (job, run) in runs {
+        let (parse, part1, part2) = (run.parse.min(), run.part1.min(), run.part2.min());
+        println!(
+            "{:<16} {:>10} {:>10} {:>10} {:>10}",
+            job.label(),
+            duration(parse),
+            duration(part1),
+            duration(part2),
+            duration(parse + part1 + part2)
+        );
+    }
+    Ok(())
+}
+
+fn bench(args: &Args) -> Result<()> {
+    println!("{} iterations per step", args.iterations.max(1));
+    println!(
+        "{:<16} {:>6} {:>10} {:>10} {:>10}",
+        "Day", "Step", "Min", "Median", "Max"
+    );
+    for job in args.jobs() {
+        let Run {
+            parse,
+            part1,
+            part2,
+            ..
+        } = (job.day.run)(job.source.read()?.as_str(), args.iterations)?;
+        for (step, samples) in [("parse", parse), ("part1", part1), ("part2", part2)] {
+            print_samples(&job, step, &samples);
+        }
+    }
+    Ok(())
+}
+
+fn verify(args: &Args) -> Result<()> {
+    let record = args.record;
+    let path = answers_path();
+    let mut recorded = AnswerFile::load(&path)?;
+
+    let (mut mismatches, mut errors, mut missing) = (Vec::new(), Vec::new(), 0);
+    println!("{:<16} {:>8} {:>8}", "Day", "Part 1", "Part 2");
+    for job in args.jobs() {
+        let answers = match job.source.read().and_then(|input| (job.day.run)(&input, 1)) {
+            Ok(run) => run.answers,
+            Err(e) => {
+                println!("{:<16} {:>8} {:>8}", job.label(), "ERROR", "ERROR");
+                errors.push((job.label(), e));
+                continue;
+            }
+        };
+        if record {
+            recorded.record(job.day.day, &job.name, &answers);
+        }
+        let mut status =
+            |part: u8, answer: &String| match recorded.get(job.day.day, &job.name, part) {
+                _ if record => "recorded",
+                None => {
+                    missing += 1;
+                    "missing"
+                }
+                Some(expected) if expected == answer => "pass",
+                Some(expected) => {
+                    mismatches.push((job.label(), part, expected.to_string(), answer.clone()));
+                    "FAIL"
+                }
+            };
+        let (part1, part2) = (status(1, &answers.part1), status(2, &answers.part2));
+        println!("{:<16} {:>8} {:>8}", job.label(), part1, part2);
+    }
+
+    if record {
+        recorded.save(&path)?;
+        println!("\nWrote {}", path.display());
+    }
+    if mismatches.is_empty() && errors.is_empty() && missing == 0 {
+        return Ok(());
+    }
+    println!();
+    for (label, part, expected, actual) in mismatches.iter() {
+        println!("Day {} part {}:", label, part);
+        println!("  expected: {}", expected.replace('\n', "\n            "));
+        println!("  actual:   {}", actual.replace('\n', "\n            "));
+    }
+    for (label, e) in errors.iter() {
+        println!("Day {} failed: {}", label, e);
+    }
+    Err(Error::msg(format!(
+        "{} answer(s) did not match {}, {} missing (run with --record), {} input(s) failed to run",
+        mismatches.len(),
+        path.display(),
+        missing,
+        errors.len()
+    )))
+}
+
+fn stream(args: &Args) -> Result<()> {
+    if args.days.iter().any(|day| day.day != 1) {
+        return Err(Error::msg("Only day 1 can be streamed"));
+    }
+    for job in args.jobs() {
+        let streamed = day01::stream(job.source.open()?, args.width, args.lenient)?;
+        println!("Day {}", job.label());
+        println!("  Rising readings: {}", streamed.readings.trend.rising);
+        println!(
+            "  Rising windows of {}: {}",
+            args.width, streamed.windows.trend.rising
+        );
+        println!("  Skipped lines: {}", streamed.skipped);
+    }
+    Ok(())
+}
+
+fn print_day(job: &Job, answers: &Answers) {
+    println!("Day {}", job.label());
+    print_part(1, &answers.part1);
+    print_part(2, &answers.part2);
+}
+
+fn print_part(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("  Part {}:", part);
+        for line in answer.lines() {
+            println!("    {}", line);
+        }
+    } else {
+        println!("  Part {}: {}", part, answer);
+    }
+}
+
+fn print_samples(job: &Job, step: &str, samples: &Samples) {
+    println!(
+        "{:<16} {:>6} {:>10} {:>10} {:>10}",
+        job.label(),
+        step,
+        duration(samples.min()),
+        duration(samples.median()),
+        duration(samples.max())
+    );
+}
+
+fn duration(d: Duration) -> String {
+    format!("{:.1?}", d)
+}
@@ -0,0 +1,131 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use eyre::{Context, Result};
+
+use crate::image::{apng, Image};
+
+/// Somewhere a step-based simulation can hand its state after every step.
+pub trait Record<S: ?Sized> {
+    fn record(&mut self, state: &S);
+}
+
+/// Records nothing, for normal runs.
+impl<S: ?Sized> Record<S> for () {
+    fn record(&mut self, _state: &S) {}
+}
+
+/// Keeps a frame of each recorded state, as turned into a frame by `capture`.
+pub struct Recorder<'a, S: ?Sized, F> {
+    capture: Box<dyn FnMut(&S) -> F + 'a>,
+    frames: Vec<F>,
+}
+
+impl<'a, S: ?Sized, F> Recorder<'a, S, F> {
+    pub fn new(capture: impl FnMut(&S) -> F + 'a) -> Recorder<'a, S, F> {
+        Recorder {
+            capture: Box::new(capture),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn frames(&self) -> &[F] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<F> {
+        self.frames
+    }
+}
+
+impl<S: ?Sized, F> Record<S> for Recorder<'_, S, F> {
+    fn record(&mut self, state: &S) {
+        let frame = (self.capture)(state);
+        self.frames.push(frame);
+    }
+}
+
+impl<S: ?Sized> Recorder<'_, S, String> {
+    /// Plays the frames back in a terminal, redrawing in place with `delay` between them.
+    pub fn replay(&self, out: &mut impl Write, delay: Duration) -> io::Result<()> {
+        for (i, frame) in self.frames.iter().enumerate() {
+            // Clear the screen and move to the top left.
+            write!(
+                out,
+                "\x1b[2J\x1b[H{}\n\nStep {}/{}\n",
+                frame,
+                i + 1,
+                self.frames.len()
+            )?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+}
+
+impl<S: ?Sized> Recorder<'_, S, Image> {
+    /// An animated PNG showing each frame for `delay`, looping forever.
+    pub fn to_apng(&self, delay: Duration) -> Result<Vec<u8>> {
+        apng(&self.frames, delay)
+    }
+
+    pub fn save_apng(&self, path: impl AsRef<Path>, delay: Duration) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_apng(delay)?).wrap_err_with(|| format!("Writing {:?}", path))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        board::{Grid, Point},
+        days::day11::{part2_recorded, Day11},
+        image::{gray, WHITE},
+        solution::Solution,
+    };
+
+    #[test]
+    fn t1() -> Result<()> {
+        let mut recorder = Recorder::new(|n: &u32| n.to_string());
+        for n in 1..=3 {
+            recorder.record(&n);
+        }
+        ().record(&4);
+        assert_eq!(["1", "2", "3"], recorder.frames());
+
+        let mut out = Vec::new();
+        recorder.replay(&mut out, Duration::ZERO)?;
+        let out = String::from_utf8(out)?;
+        assert_eq!(3, out.matches("\x1b[2J").count());
+        assert!(out.ends_with("3\n\nStep 3/3\n"));
+
+        let mut pictures = Recorder::new(|n: &u32| Image::new(2, 1, gray(*n, 2)));
+        pictures.record(&0);
+        pictures.record(&2);
+        assert_eq!(WHITE, pictures.frames()[1].get(&Point { x: 1, y: 0 }));
+        let png = pictures.to_apng(Duration::from_millis(250))?;
+        assert_eq!(1, png.windows(4).filter(|w| w == b"acTL").count());
+        assert_eq!(2, png.windows(4).filter(|w| w == b"fcTL").count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let octopuses = Day11::parse(include_str!("../days/day11/example.txt"))?;
+        let mut flashes =
+            Recorder::new(|grid: &Grid<u32>| Image::from_cells(grid, |v| gray(*v, 9)));
+        assert_eq!(195, part2_recorded(octopuses, &mut flashes));
+        assert_eq!(195, flashes.frames().len());
+        let png = flashes.to_apng(Duration::from_millis(50))?;
+        assert_eq!(195, png.windows(4).filter(|w| w == b"fcTL").count());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
+
+use itertools::Itertools;
+
+use crate::board::{Cells, Point};
+
+/// ANSI foreground colors for highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn code(&self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// Draws a `Board` or `Grid` as text, one line per row. Builder-style: configure, then
+/// `render` as many times as needed.
+pub struct Renderer<'a, T> {
+    format: Box<dyn Fn(&T) -> String + 'a>,
+    width: usize,
+    auto_width: bool,
+    separator: &'a str,
+    highlights: HashMap<Point, Color>,
+    plain: bool,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    /// Each cell drawn as `format` returns it.
+    pub fn new(format: impl Fn(&T) -> String + 'a) -> Renderer<'a, T> {
+        Renderer {
+            format: Box::new(format),
+            width: 0,
+            auto_width: false,
+            separator: "",
+            highlights: HashMap::new(),
+            plain: false,
+        }
+    }
+
+    /// Each cell drawn with its `Display` impl.
+    pub fn display() -> Renderer<'a, T>
+    where
+        T: Display,
+    {
+        Renderer::new(|v: &T| v.to_string())
+    }
+
+    /// Each cell drawn with its `Debug` impl.
+    pub fn debug() -> Renderer<'a, T>
+    where
+        T: Debug,
+    {
+        Renderer::new(|v: &T| format!("{:?}", v))
+    }
+
+    /// Right-aligns cells to at least `width` characters.
+    pub fn width(self, width: usize) -> Renderer<'a, T> {
+        Renderer { width, ..self }
+    }
+
+    /// Right-aligns cells to the widest one on the board.
+    pub fn auto_width(self) -> Renderer<'a, T> {
+        Renderer {
+            auto_width: true,
+            ..self
+        }
+    }
+
+    /// Put between neighboring cells on a row.
+    pub fn separator(self, separator: &'a str) -> Renderer<'a, T> {
+        Renderer { separator, ..self }
+    }
+
+    /// Colors `points`. Points highlighted more than once take the latest color.
+    pub fn highlight(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        color: Color,
+    ) -> Renderer<'a, T> {
+        self.highlights
+            .extend(points.into_iter().map(|p| (p, color)));
+        self
+    }
+
+    /// No escape codes, so the output can be compared in tests or saved. Highlights are
+    /// dropped.
+    pub fn plain(self) -> Renderer<'a, T> {
+        Renderer {
+            plain: true,
+            ..self
+        }
+    }
+
+    pub fn render<C: Cells<Cell = T>>(&self, cells: &C) -> String {
+        let rows = (0..cells.height())
+            .map(|y| {
+                (0..cells.width())
+                    .map(|x| {
+                        let p = Point { x, y };
+                        (p, (self.format)(cells.get(&p)))
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+        let widest = rows
+            .iter()
+            .flatten()
+            .map(|(_, text)| text.chars().count())
+            .max()
+            .filter(|_| self.auto_width)
+            .unwrap_or(0);
+        let width = self.width.max(widest);
+
+        rows.into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|(p, text)| {
+                        let text = format!("{:>width$}", text, width = width);
+                        match self.highlights.get(&p).filter(|_| !self.plain) {
+                            Some(color) => format!("\x1b[{}m{}\x1b[0m", color.code(), text),
+                            None => text,
+                        }
+                    })
+                    .join(self.separator)
+            })
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::board::{Board, Grid};
+    use eyre::Result;
+
+    #[test]
+    fn t1() -> Result<()> {
+        let grid: Grid<u32> = "1 100\n23 4".parse()?;
+
+        assert_eq!("1100\n234", Renderer::display().render(&grid));
+        let padded = Renderer::display().auto_width().separator(" ");
+        assert_eq!("  1 100\n 23   4", padded.render(&grid));
+        let wide = Renderer::display().width(2).separator("|");
+        assert_eq!(" 1|100\n23| 4", wide.render(&grid));
+
+        let board = Board::new([[true, false], [false, true]]);
+        let map = Renderer::new(|b: &bool| if *b { "#" } else { "." }.to_string());
+        assert_eq!("#.\n.#", map.render(&board));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let grid: Grid<u32> = "1 2\n3 4".parse()?;
+        let path = [Point { x: 1, y: 0 }, Point { x: 1, y: 1 }];
+
+        let colored = Renderer::display()
+            .highlight(path, Color::Red)
+            .highlight([Point { x: 1, y: 1 }], Color::Green);
+        assert_eq!(
+            "1\x1b[31m2\x1b[0m\n3\x1b[32m4\x1b[0m",
+            colored.render(&grid)
+        );
+        assert_eq!("12\n34", colored.plain().render(&grid));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,358 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use crate::board::{Cells, Coord, Grid, Point};
+
+/// Everything reached from `start`, with the cost to get there and the step it came from.
+pub struct Search<K> {
+    start: Point,
+    costs: Grid<Option<K>>,
+    prev: Grid<Option<Point>>,
+}
+
+impl<K: Copy> Search<K> {
+    fn new(map: &impl Cells, start: Point) -> Search<K> {
+        Search {
+            start,
+            costs: Grid::new(map.width(), map.height(), None),
+            prev: Grid::new(map.width(), map.height(), None),
+        }
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// The cost of reaching `p`, or `None` if it wasn't reached.
+    pub fn cost(&self, p: &Point) -> Option<K> {
+        *self.costs.get(p)
+    }
+
+    /// The points from `start` to `p` inclusive, or `None` if `p` wasn't reached.
+    pub fn path(&self, p: &Point) -> Option<Vec<Point>> {
+        self.cost(p)?;
+        let mut path = vec![*p];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(*prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every reached point, in row order.
+    pub fn reached(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.costs.height())
+            .flat_map(move |y| (0..self.costs.width()).map(move |x| Point { x, y }))
+            .filter(|p| self.costs.get(p).is_some())
+    }
+}
+
+/// A shortest route between two points and what it cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub cost: u64,
+    pub points: Vec<Point>,
+}
+
+/// Breadth-first distances from `start`, only stepping where `can_step(from, to)` allows.
+pub fn bfs<C: Cells>(
+    map: &C,
+    start: Point,
+    mut can_step: impl FnMut(&Point, &Point) -> bool,
+) -> Search<usize> {
+    let mut search = Search::new(map, start);
+    search.costs.set(&start, Some(0));
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((p, dist)) = queue.pop_front() {
+        for n in map.neighbors(&p) {
+            if search.costs.get(&n).is_none() && can_step(&p, &n) {
+                search.costs.set(&n, Some(dist + 1));
+                search.prev.set(&n, Some(p));
+                queue.push_back((n, dist + 1));
+            }
+        }
+    }
+    search
+}
+
+/// The points connected to `start` through cells matching `include`, in the order they
+/// were reached. Empty if `start` itself doesn't match.
+pub fn flood_fill<C: Cells>(
+    map: &C,
+    start: Point,
+    mut include: impl FnMut(&C::Cell) -> bool,
+) -> Vec<Point> {
+    let mut seen = Grid::new(map.width(), map.height(), false);
+    fill(map, start, &mut include, &mut seen)
+}
+
+fn fill<C: Cells>(
+    map: &C,
+    start: Point,
+    include: &mut impl FnMut(&C::Cell) -> bool,
+    seen: &mut Grid<bool>,
+) -> Vec<Point> {
+    if *seen.get(&start) || !include(map.get(&start)) {
+        return Vec::new();
+    }
+    seen.set(&start, true);
+    let mut filled = vec![start];
+    let mut i = 0;
+    while let Some(p) = filled.get(i).copied() {
+        for n in map.neighbors(&p) {
+            if !*seen.get(&n) && include(map.get(&n)) {
+                seen.set(&n, true);
+                filled.push(n);
+            }
+        }
+        i += 1;
+    }
+    filled
+}
+
+/// Cells matching `include`, split into orthogonally connected groups.
+pub struct Components {
+    labels: Grid<Option<usize>>,
+    members: Vec<Vec<Point>>,
+}
+
+impl Components {
+    /// Which group `p` belongs to, or `None` if it didn't match.
+    pub fn label(&self, p: &Point) -> Option<usize> {
+        *self.labels.get(p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// The points of group `label`.
+    pub fn members(&self, label: usize) -> &[Point] {
+        &self.members[label]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[Point]> {
+        self.members.iter().map(|m| m.as_slice())
+    }
+}
+
+/// Labels every group of connected cells matching `include`, numbered in row order of
+/// their first cell.
+pub fn components<C: Cells>(map: &C, mut include: impl FnMut(&C::Cell) -> bool) -> Components {
+    let mut seen = Grid::new(map.width(), map.height(), false);
+    let mut labels = Grid::new(map.width(), map.height(), None);
+    let mut members = Vec::new();
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let group = fill(map, Point { x, y }, &mut include, &mut seen);
+            if group.is_empty() {
+                continue;
+            }
+            for p in group.iter() {
+                labels.set(p, Some(members.len()));
+            }
+            members.push(group);
+        }
+    }
+    Components { labels, members }
+}
+
+/// A group of connected cells and a summary of what's in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<T> {
+    pub members: Vec<Point>,
+    /// Top-left and bottom-right corners of the bounding box, inclusive.
+    pub bounds: (Point, Point),
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> Region<T> {
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+}
+
+/// Splits the map into regions separated by cells matching `is_wall`. Walls belong to no
+/// region.
+pub fn regions<C>(map: &C, mut is_wall: impl FnMut(&C::Cell) -> bool) -> Vec<Region<C::Cell>>
+where
+    C: Cells,
+    C::Cell: Copy + Ord,
+{
+    components(map, |v| !is_wall(v))
+        .members
+        .into_iter()
+        .map(|members| {
+            let (mut lo, mut hi) = (members[0], members[0]);
+            let (mut min, mut max) = (*map.get(&lo), *map.get(&lo));
+            for p in members.iter() {
+                lo = Point {
+                    x: lo.x.min(p.x),
+                    y: lo.y.min(p.y),
+                };
+                hi = Point {
+                    x: hi.x.max(p.x),
+                    y: hi.y.max(p.y),
+                };
+                min = min.min(*map.get(p));
+                max = max.max(*map.get(p));
+            }
+            Region {
+                members,
+                bounds: (lo, hi),
+                min,
+                max,
+            }
+        })
+        .collect()
+}
+
+/// Cheapest path from `start` to `goal`, where entering a cell costs its value.
+pub fn dijkstra<C>(map: &C, start: Point, goal: Point) -> Option<Path>
+where
+    C: Cells,
+    C::Cell: Copy + Into<u64>,
+{
+    astar(map, start, goal, |_| 0)
+}
+
+/// As `dijkstra`, guided by `heuristic`, which must never overestimate the remaining cost.
+pub fn astar<C>(
+    map: &C,
+    start: Point,
+    goal: Point,
+    heuristic: impl Fn(&Point) -> u64,
+) -> Option<Path>
+where
+    C: Cells,
+    C::Cell: Copy + Into<u64>,
+{
+    shortest_path(map, start, goal, |p| Some((*map.get(p)).into()), heuristic)
+}
+
+/// A* with a caller-supplied cost of entering each cell; `None` makes a cell impassable.
+pub fn shortest_path<C: Cells>(
+    map: &C,
+    start: Point,
+    goal: Point,
+    mut cost: impl FnMut(&Point) -> Option<u64>,
+    heuristic: impl Fn(&Point) -> u64,
+) -> Option<Path> {
+    let mut search = Search::new(map, start);
+    search.costs.set(&start, Some(0));
+    // Points go in as (y, x) so ties are broken in row order.
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start.y, start.x))]);
+    while let Some(Reverse((_, spent, y, x))) = queue.pop() {
+        let p = Point { x, y };
+        if p == goal {
+            return Some(Path {
+                cost: spent,
+                points: search.path(&goal)?,
+            });
+        }
+        if search.cost(&p).is_some_and(|best| best < spent) {
+            continue;
+        }
+        for n in map.neighbors(&p) {
+            let Some(step) = cost(&n) else { continue };
+            let total = spent + step;
+            if search.cost(&n).is_none_or(|best| total < best) {
+                search.costs.set(&n, Some(total));
+                search.prev.set(&n, Some(p));
+                queue.push(Reverse((total + heuristic(&n), total, n.y, n.x)));
+            }
+        }
+    }
+    None
+}
+
+/// Taxicab distance, an admissible A* heuristic when every step costs at least 1.
+pub fn manhattan(a: &Point, b: &Point) -> u64 {
+    Coord::from(*a).manhattan(&Coord::from(*b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::board::{IntBoard, IntGrid};
+    use eyre::Result;
+
+    fn pt(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    #[test]
+    fn t1() -> Result<()> {
+        let grid = "1163\n1381\n2136\n3694".parse::<IntGrid>()?.0;
+
+        let search = bfs(&grid, pt(0, 0), |_, to| grid.get(to) != &6);
+        assert_eq!(Some(4), search.cost(&pt(2, 2)));
+        assert_eq!(None, search.cost(&pt(2, 0)));
+        assert_eq!(Some(vec![pt(0, 0)]), search.path(&pt(0, 0)));
+        assert_eq!(5, search.path(&pt(2, 2)).unwrap().len());
+        assert_eq!(13, search.reached().count());
+
+        let low = flood_fill(&grid, pt(0, 0), |v| *v < 3);
+        assert_eq!(5, low.len());
+        assert!(flood_fill(&grid, pt(3, 0), |v| *v < 3).is_empty());
+
+        let groups = components(&grid, |v| *v < 3);
+        assert_eq!(2, groups.len());
+        assert_eq!(Some(1), groups.label(&pt(3, 1)));
+        assert_eq!(None, groups.label(&pt(2, 0)));
+        assert_eq!(&[pt(3, 1)], groups.members(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let grid = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n\
+                    1319128137\n1359912421\n3125421639\n1293138521\n2311944581"
+            .parse::<IntGrid>()?
+            .0;
+        let goal = pt(9, 9);
+
+        let path = dijkstra(&grid, pt(0, 0), goal).unwrap();
+        assert_eq!(40, path.cost);
+        assert_eq!(Some(&pt(0, 0)), path.points.first());
+        assert_eq!(Some(&goal), path.points.last());
+        let entered = path.points[1..].iter().map(|p| *grid.get(p) as u64);
+        assert_eq!(40, entered.sum::<u64>());
+
+        let guided = astar(&grid, pt(0, 0), goal, |p| manhattan(p, &goal)).unwrap();
+        assert_eq!(40, guided.cost);
+
+        let walled = shortest_path(&grid, pt(0, 0), goal, |p| (p.x != 5).then_some(1), |_| 0);
+        assert_eq!(None, walled);
+
+        Ok(())
+    }
+
+    #[test]
+    fn t3() -> Result<()> {
+        let board = "2199943210\n3987894921\n9856789892\n8767896789\n9899965678"
+            .parse::<IntBoard<10, 5>>()?
+            .0;
+
+        let found = regions(&board, |v| *v == 9);
+        assert_eq!(
+            vec![3, 9, 14, 9],
+            found.iter().map(|r| r.size()).collect::<Vec<_>>()
+        );
+        assert_eq!((pt(0, 0), pt(1, 1)), found[0].bounds);
+        assert_eq!((1, 3), (found[0].min, found[0].max));
+        assert_eq!((pt(5, 0), pt(9, 2)), found[1].bounds);
+        assert_eq!((0, 4), (found[1].min, found[1].max));
+        assert!(found[2].members.contains(&pt(2, 2)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use eyre::Result;
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// A single day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Wall-clock samples for one step of a run, one per iteration.
+#[derive(Debug, Clone, Default)]
+pub struct Samples(Vec<Duration>);
+impl Samples {
+    pub fn min(&self) -> Duration {
+        self.0.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.0.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.0.clone();
+        sorted.sort();
+        sorted.get(sorted.len() / 2).copied().unwrap_or_default()
+    }
+
+    fn time<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let out = f();
+        self.0.push(start.elapsed());
+        out
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answers: Answers,
+    pub parse: Samples,
+    pub part1: Samples,
+    pub part2: Samples,
+}
+
+/// Type-erased entry point for a day, so days with different inputs can share a registry.
+/// The second argument is how many times each step is repeated for timing.
+pub type Runner = fn(&str, usize) -> Result<Run>;
+
+pub fn solve<S: Solution>(input: &str, iterations: usize) -> Result<Run> {
+    let iterations = iterations.max(1);
+    let (mut parse, mut part1, mut part2) =
+        (Samples::default(), Samples::default(), Samples::default());
+
+    let mut parsed = parse.time(|| S::parse(input))?;
+    for _ in 1..iterations {
+        parsed = parse.time(|| S::parse(black_box(input)))?;
+    }
+
+    let mut answer1 = part1.time(|| S::part1(&parsed));
+    for _ in 1..iterations {
+        answer1 = part1.time(|| S::part1(black_box(&parsed)));
+    }
+
+    let mut answer2 = part2.time(|| S::part2(&parsed));
+    for _ in 1..iterations {
+        answer2 = part2.time(|| S::part2(black_box(&parsed)));
+    }
+
+    Ok(Run {
+        answers: Answers {
+            part1: answer1.to_string(),
+            part2: answer2.to_string(),
+        },
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn t1() {
+        let samples = Samples([5, 1, 3, 9, 7].map(Duration::from_millis).to_vec());
+
+        assert_eq!(Duration::from_millis(1), samples.min());
+        assert_eq!(Duration::from_millis(5), samples.median());
+        assert_eq!(Duration::from_millis(9), samples.max());
+    }
+
+    #[test]
+    fn t2() -> Result<()> {
+        let run = crate::days::get(1).expect("day 1").run;
+        let run = run("1\n2\n3\n4", 3)?;
+
+        assert_eq!("3", run.answers.part1);
+        assert_eq!(3, run.parse.0.len());
+        assert_eq!(3, run.part2.0.len());
+
+        Ok(())
+    }
+}